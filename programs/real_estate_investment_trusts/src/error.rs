@@ -15,11 +15,167 @@ pub enum RealEstateInvestmentTrustsError {
     InvalidAmount,
     #[msg("Invalid numeric value.")]
     InvalidNumeric,
+    #[msg("Trust scheme is not listed.")]
+    SchemeNotListed,
+    #[msg("Invalid trust scheme status transition.")]
+    InvalidSchemeStatusTransition,
+    #[msg("Invalid trust scheme id.")]
+    InvalidSchemeId,
+    #[msg("Trust scheme is not delisted.")]
+    SchemeNotDelisted,
+    #[msg("Net asset valuation is older than the maximum age.")]
+    ValuationStale,
 
     //
     #[msg("Invalid country length")]
     InvalidCountryLength,
 
+    // offering
+    #[msg("Offering is already configured.")]
+    OfferingAlreadyConfigured,
+    #[msg("Invalid offering caps.")]
+    InvalidOfferingCaps,
+    #[msg("Invalid offering window.")]
+    InvalidOfferingWindow,
+    #[msg("Offering is not open.")]
+    OfferingNotOpen,
+    #[msg("Offering has not closed yet.")]
+    OfferingNotClosed,
+    #[msg("Offering did not succeed.")]
+    OfferingNotSucceeded,
+    #[msg("Offering did not fail.")]
+    OfferingNotFailed,
+    #[msg("Offering is still in progress.")]
+    OfferingInProgress,
+    #[msg("Subscription is below the minimum ticket.")]
+    BelowMinimumTicket,
+    #[msg("Subscription exceeds the hard cap.")]
+    HardCapExceeded,
+    #[msg("Subscription is already claimed.")]
+    SubscriptionAlreadyClaimed,
+
+    // distributions
+    #[msg("Only income REITs pay distributions.")]
+    NotIncomeReit,
+    #[msg("Trust scheme has no units issued.")]
+    NoUnitsIssued,
+    #[msg("Distribution expiry is earlier than a previous distribution.")]
+    DistributionExpiryNotMonotonic,
+    #[msg("Distribution has not expired.")]
+    DistributionNotExpired,
+    #[msg("Distribution is not the next one to sweep.")]
+    DistributionNotSweepable,
+    #[msg("Distribution has expired.")]
+    DistributionExpired,
+    #[msg("Distribution is not the oldest one accrued by the holding.")]
+    DistributionMismatch,
+    #[msg("Nothing to claim.")]
+    NothingToClaim,
+    #[msg("Distributions are reinvested for this holding.")]
+    DripElected,
+    #[msg("Holding has not elected to reinvest distributions.")]
+    DripNotElected,
+    #[msg("Accrued distributions do not cover a unit.")]
+    NothingToReinvest,
+
+    // payout ratio
+    #[msg("Invalid income period.")]
+    InvalidIncomePeriod,
+    #[msg("Income epoch does not belong to the trust scheme.")]
+    IncomeEpochMismatch,
+    #[msg("Income epoch is already flagged.")]
+    IncomeEpochAlreadyFlagged,
+    #[msg("Payout deadline has not been reached.")]
+    PayoutDeadlineNotReached,
+    #[msg("Payout ratio is met.")]
+    PayoutRatioMet,
+    #[msg("Trust scheme has missed its payout ratio.")]
+    SchemeNonCompliant,
+
+    // redemptions
+    #[msg("Redemption request has unsettled windows.")]
+    RedemptionPending,
+    #[msg("Redemption window is still open.")]
+    RedemptionWindowOpen,
+    #[msg("No units are queued for redemption.")]
+    NothingToRedeem,
+    #[msg("Redemption window does not match the request.")]
+    RedemptionWindowMismatch,
+
+    // order book
+    #[msg("Order does not have enough units.")]
+    InsufficientOrderUnits,
+    #[msg("Order cannot be filled by its seller.")]
+    SelfTrade,
+
+    // liquidity pool
+    #[msg("Liquidity provider is not whitelisted.")]
+    LiquidityProviderNotWhitelisted,
+    #[msg("Pool has no liquidity.")]
+    PoolEmpty,
+    #[msg("Pool price is outside the band around net asset value.")]
+    PoolPriceOutOfBand,
+    #[msg("Price moved beyond the given limit.")]
+    SlippageExceeded,
+    #[msg("Insufficient pool shares.")]
+    InsufficientShares,
+
+    // attestation
+    #[msg("Investor has no attestation.")]
+    AttestationMissing,
+    #[msg("Investor attestation has expired.")]
+    AttestationExpired,
+    #[msg("Invalid KYC level.")]
+    InvalidKycLevel,
+    #[msg("Invalid attestation expiry.")]
+    InvalidAttestationExpiry,
+    #[msg("Investor does not own the token account.")]
+    InvestorMismatch,
+
+    // jurisdictions
+    #[msg("Investor country is on the sanctioned country list.")]
+    CountrySanctioned,
+    #[msg("Investor country is not on the scheme's allow list.")]
+    CountryNotAllowed,
+    #[msg("Investor country is on the scheme's deny list.")]
+    CountryDenied,
+    #[msg("Trust scheme jurisdiction policy was not provided.")]
+    JurisdictionPolicyMissing,
+    #[msg("Too many country codes.")]
+    TooManyCountries,
+
+    // transfer hook
+    #[msg("Unit mint has no transfer hook.")]
+    TransferHookNotEnabled,
+    #[msg("Unit mint does not belong to the trust scheme.")]
+    UnitMintMismatch,
+    #[msg("Transfer hook was not invoked by a token transfer.")]
+    NotTransferring,
+    #[msg("Token account owner is not a registered investor.")]
+    InvestorNotRegistered,
+    #[msg("Units can only be transferred through the program.")]
+    TransferNotAuthorized,
+
+    // token metadata
+    #[msg("Unit mint has no token metadata.")]
+    TokenMetadataNotEnabled,
+    #[msg("Invalid metadata uri length")]
+    InvalidUriLength,
+
+    // compliance actions
+    #[msg("Compliance action requires a reason.")]
+    InvalidComplianceReason,
+    #[msg("Holding is already frozen.")]
+    HoldingAlreadyFrozen,
+    #[msg("Holding is not frozen.")]
+    HoldingNotFrozen,
+    #[msg("Holding is frozen by a compliance officer.")]
+    HoldingFrozen,
+    #[msg("Unit mint has no permanent delegate.")]
+    ForcedTransferNotEnabled,
+    #[msg("Units cannot be force transferred to the same holder.")]
+    InvalidForcedTransferRecipient,
+
     // Arithmetic
     #[msg("Arithmetic operation failed.")]
     InvalidArithmeticOperation,
@@ -32,9 +188,37 @@ pub enum RealEstateInvestmentTrustsError {
     #[msg("Insufficient funds.")]
     InsufficientFunds,
 
+    // holding
+    #[msg("Insufficient units.")]
+    InsufficientUnits,
+    #[msg("Units are still under lock-up.")]
+    HoldingLocked,
+    #[msg("Units cannot be transferred to the same investor.")]
+    InvalidTransferRecipient,
+
     // account
     #[msg("Account is not initialized.")]
     AccountNotInitialized,
     #[msg("Account is already initialized.")]
     AccountAlreadyInitialized,
+    #[msg("Account is already migrated.")]
+    AccountAlreadyMigrated,
+    #[msg("Account layout is not recognized.")]
+    InvalidAccountLayout,
+
+    // roles
+    #[msg("Signer does not hold the required role.")]
+    Unauthorized,
+    #[msg("Role is already granted to this authority.")]
+    RoleAlreadyGranted,
+    #[msg("Role is not granted to this authority.")]
+    RoleNotFound,
+    #[msg("Role registry is full.")]
+    RoleRegistryFull,
+    #[msg("Invalid role for this operation.")]
+    InvalidRole,
+    #[msg("No admin transfer is pending for this authority.")]
+    AdminTransferNotPending,
+    #[msg("Program is paused.")]
+    ProgramPaused,
 }
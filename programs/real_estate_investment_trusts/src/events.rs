@@ -0,0 +1,37 @@
+//! Program events
+
+use {crate::state::compliance_reason::ComplianceReason, anchor_lang::prelude::*};
+
+// a compliance officer froze a holder's unit token account
+#[event]
+pub struct HoldingFrozen {
+    pub real_estate_investment_trust_scheme: Pubkey,
+    pub holding: Pubkey,
+    pub token_account: Pubkey,
+    pub reason: ComplianceReason,
+    pub officer: Pubkey,
+    pub timestamp: i64,
+}
+
+// a compliance officer thawed a holder's unit token account
+#[event]
+pub struct HoldingThawed {
+    pub real_estate_investment_trust_scheme: Pubkey,
+    pub holding: Pubkey,
+    pub token_account: Pubkey,
+    pub reason: ComplianceReason,
+    pub officer: Pubkey,
+    pub timestamp: i64,
+}
+
+// a compliance officer moved units between holdings without the holder's signature
+#[event]
+pub struct UnitsForceTransferred {
+    pub real_estate_investment_trust_scheme: Pubkey,
+    pub from_holding: Pubkey,
+    pub to_holding: Pubkey,
+    pub units: u64,
+    pub reason: ComplianceReason,
+    pub officer: Pubkey,
+    pub timestamp: i64,
+}
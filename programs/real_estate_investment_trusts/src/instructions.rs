@@ -1,16 +1,76 @@
 // admin instructions
+pub mod accept_admin_role;
+pub mod grant_role;
 pub mod init;
+pub mod migrate_account;
+pub mod revoke_role;
+pub mod set_paused;
+pub mod set_sanctioned_countries;
+pub mod transfer_role;
+pub mod update_configs;
 
-// public instructions
-pub mod buy_investment_trusts;
+// scheme approver instructions
+pub mod deregister_issuer;
+pub mod set_scheme_status;
+
+// compliance officer instructions
+pub mod attest_investor;
+pub mod force_transfer;
+pub mod set_holding_frozen;
+pub mod set_investor_status;
+
+// valuer instructions
+pub mod update_nav;
+
+// scheme promoter instructions
+pub mod configure_offering;
+pub mod configure_pool;
 pub mod create_token;
+pub mod declare_distribution;
+pub mod initialize_extra_account_meta_list;
+pub mod post_income_epoch;
 pub mod register_investment_trust_scheme;
+pub mod set_jurisdiction_policy;
+pub mod set_liquidity_provider;
+pub mod sweep_distribution;
+pub mod update_token_metadata;
+
+// public instructions
+pub mod add_liquidity;
+pub mod buy_investment_trusts;
+pub mod cancel_ask;
+pub mod check_payout_compliance;
+pub mod claim_distribution;
+pub mod claim_subscription;
+pub mod close_redemption_window;
+pub mod fill_ask;
+pub mod finalize_offering;
+pub mod post_ask;
+pub mod refund_subscription;
 pub mod register_investor;
+pub mod reinvest_distributions;
+pub mod remove_liquidity;
 pub mod sell_investment_trusts;
+pub mod set_drip_election;
+pub mod settle_redemption;
+pub mod subscribe_offering;
+pub mod swap;
+pub mod transfer_hook;
 pub mod transfer_token;
 
 // bring everything in scope
 pub use {
-    buy_investment_trusts::*, create_token::*, init::*, register_investment_trust_scheme::*,
-    register_investor::*, sell_investment_trusts::*, transfer_token::*,
+    accept_admin_role::*, add_liquidity::*, attest_investor::*, buy_investment_trusts::*,
+    cancel_ask::*, check_payout_compliance::*, claim_distribution::*, claim_subscription::*,
+    close_redemption_window::*, configure_offering::*, configure_pool::*, create_token::*,
+    declare_distribution::*, deregister_issuer::*, fill_ask::*, finalize_offering::*,
+    force_transfer::*, grant_role::*, init::*, initialize_extra_account_meta_list::*,
+    migrate_account::*, post_ask::*, post_income_epoch::*, refund_subscription::*,
+    register_investment_trust_scheme::*, register_investor::*, reinvest_distributions::*,
+    remove_liquidity::*, revoke_role::*, sell_investment_trusts::*, set_drip_election::*,
+    set_holding_frozen::*, set_investor_status::*, set_jurisdiction_policy::*,
+    set_liquidity_provider::*, set_paused::*, set_sanctioned_countries::*, set_scheme_status::*,
+    settle_redemption::*, subscribe_offering::*, swap::*, sweep_distribution::*, transfer_hook::*,
+    transfer_role::*, transfer_token::*, update_configs::*, update_nav::*,
+    update_token_metadata::*,
 };
@@ -0,0 +1,28 @@
+//! AcceptAdminRole instruction handler
+
+use {
+    crate::{error::RealEstateInvestmentTrustsError, state::configs::InvestmentTrustsConfigs},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct AcceptAdminRole<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.pending_admin == owner.key() @ RealEstateInvestmentTrustsError::AdminTransferNotPending
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // the admin proposed by transfer_role
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn accept_admin_role(ctx: Context<AcceptAdminRole>) -> Result<()> {
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    investment_trusts_configs.admin = investment_trusts_configs.pending_admin;
+    investment_trusts_configs.pending_admin = Pubkey::default();
+
+    Ok(())
+}
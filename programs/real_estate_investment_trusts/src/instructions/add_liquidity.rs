@@ -0,0 +1,254 @@
+//! AddLiquidity instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{isqrt, quote_to_base_units, units_to_base_units},
+        state::{
+            configs::InvestmentTrustsConfigs,
+            holding::Holding,
+            investor::Investor,
+            pool::{LiquidityProvider, Pool},
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+        token::{transfer_checked_with_hook, unit_transfer_authority, with_transfer_fee},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: AddLiquidityParams)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Box<Account<'info, InvestmentTrustsConfigs>>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Box<Account<'info, RealEstateInvestmentTrustScheme>>,
+    #[account(mut, has_one = unit_vault, has_one = quote_vault,
+        seeds = [b"pool", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut, has_one = owner,
+        constraint = liquidity_provider.whitelisted @ RealEstateInvestmentTrustsError::LiquidityProviderNotWhitelisted,
+        seeds = [b"liquidity-provider", pool.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub liquidity_provider: Box<Account<'info, LiquidityProvider>>,
+    #[account(mut, has_one = owner,
+        constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub investor: Box<Account<'info, Investor>>,
+    #[account(mut,
+        constraint = holding.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Box<Account<'info, Holding>>,
+    #[account(mut)]
+    pub unit_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    // liquidity provider's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: Box<InterfaceAccount<'info, TokenAccount>>,
+    // liquidity provider's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub sender_tokens: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: Box<InterfaceAccount<'info, Mint>>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associate_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddLiquidityParams {
+    pub units: u64,     // whole units of investment trusts deposited
+    pub max_quote: u64, // most quote tokens deposited alongside, fixed-point quote value
+}
+
+pub fn add_liquidity<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+    params: &AddLiquidityParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.units == 0 || params.max_quote == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let pool = &mut ctx.accounts.pool;
+    let liquidity_provider = &mut ctx.accounts.liquidity_provider;
+    let investor = &mut ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+    let decimals = ctx.accounts.mint_token.decimals;
+    let unit_investment_trusts = params.units;
+    let max_quote = quote_to_base_units(params.max_quote, decimals)?;
+
+    // the pool is priced against the latest valuation, which must not be stale
+    let now = Clock::get()?.unix_timestamp;
+    real_estate_investment_trust_scheme
+        .check_valuation_fresh(ctx.accounts.investment_trusts_configs.max_nav_age, now)?;
+
+    // only investors with a current attestation may trade
+    investor.check_attestation(now)?;
+
+    // pooled units leave the position, subject to the same lock-up as a sale
+    if now < holding.locked_until {
+        return Err(RealEstateInvestmentTrustsError::HoldingLocked.into());
+    }
+
+    if unit_investment_trusts > holding.free_units()? {
+        return Err(RealEstateInvestmentTrustsError::InsufficientUnits.into());
+    }
+
+    // the first deposit pays max_quote and sets the pool price, taking any
+    // reserves left after the last withdrawal along with its shares,
+    // later deposits keep the pool price, rounding in favour of the pool
+    let (amount, shares) = if pool.total_shares == 0 {
+        let unit_reserve = (pool.unit_reserve as u128)
+            .checked_add(unit_investment_trusts as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let quote_reserve = (pool.quote_reserve as u128)
+            .checked_add(max_quote as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let shares = isqrt(
+            unit_reserve
+                .checked_mul(quote_reserve)
+                .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?,
+        );
+        (max_quote as u128, shares)
+    } else {
+        let unit_reserve = pool.unit_reserve as u128;
+        let amount = (pool.quote_reserve as u128)
+            .checked_mul(unit_investment_trusts as u128)
+            .and_then(|value| value.checked_add(unit_reserve - 1))
+            .map(|value| value / unit_reserve)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let shares = (pool.total_shares as u128)
+            .checked_mul(unit_investment_trusts as u128)
+            .map(|value| value / unit_reserve)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        (amount, shares)
+    };
+
+    if amount > max_quote as u128 {
+        return Err(RealEstateInvestmentTrustsError::SlippageExceeded.into());
+    }
+    let amount = amount as u64;
+    let shares = u64::try_from(shares)
+        .map_err(|_| RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    if shares == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    // pool
+    pool.unit_reserve = pool
+        .unit_reserve
+        .checked_add(unit_investment_trusts)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    pool.quote_reserve = pool
+        .quote_reserve
+        .checked_add(amount)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    pool.total_shares = pool
+        .total_shares
+        .checked_add(shares)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    real_estate_investment_trust_scheme.pooled_units = pool.unit_reserve;
+    pool.check_price_band(quote_to_base_units(
+        real_estate_investment_trust_scheme.unit_cost_of_investment_trusts,
+        decimals,
+    )?)?;
+
+    liquidity_provider.shares = liquidity_provider
+        .shares
+        .checked_add(shares)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // pooled units leave the position with the cost paid for them
+    holding.debit(
+        real_estate_investment_trust_scheme,
+        investor,
+        unit_investment_trusts,
+    )?;
+
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.investor_units.to_account_info(),
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.unit_vault.to_account_info(),
+                authority: unit_transfer_authority(
+                    real_estate_investment_trust_scheme,
+                    &ctx.accounts.owner.to_account_info(),
+                ),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        units,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    // the provider covers any transfer fee so the pool holds the full reserve
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.sender_tokens.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.quote_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        with_transfer_fee(&ctx.accounts.mint_token, amount)?,
+        decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+//! AttestInvestor instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{configs::InvestmentTrustsConfigs, investor::Investor, role::Role},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: AttestInvestorParams)]
+pub struct AttestInvestor<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::ComplianceOfficer) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut)]
+    pub investor: Account<'info, Investor>,
+    // compliance officer
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AttestInvestorParams {
+    pub kyc_level: u8,          // KYC tier the investor was verified to
+    pub expires_at: i64,        // unix timestamp the attestation expires
+    pub accredited: bool,       // accredited investor status
+    pub dossier_hash: [u8; 32], // hash of the off-chain KYC/AML dossier
+}
+
+pub fn attest_investor(ctx: Context<AttestInvestor>, params: &AttestInvestorParams) -> Result<()> {
+    msg!("Validate inputs");
+    if params.kyc_level == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidKycLevel.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if params.expires_at <= now {
+        return Err(RealEstateInvestmentTrustsError::InvalidAttestationExpiry.into());
+    }
+
+    let investor = &mut ctx.accounts.investor;
+
+    // a fresh attestation replaces the previous one and activates the investor
+    investor.kyc_level = params.kyc_level;
+    investor.kyc_expires_at = params.expires_at;
+    investor.accredited = params.accredited;
+    investor.dossier_hash = params.dossier_hash;
+    investor.attested_at = now;
+    investor.active = true;
+
+    Ok(())
+}
@@ -3,46 +3,99 @@
 use {
     crate::{
         error::RealEstateInvestmentTrustsError,
+        math::{quote_to_base_units, quote_value, units_to_base_units},
         state::{
-            investor::Investor,
+            configs::InvestmentTrustsConfigs, holding::Holding, investor::Investor,
+            jurisdiction_policy::JurisdictionPolicy, offering::OfferingStatus,
             real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
         },
+        token::with_transfer_fee,
     },
     anchor_lang::prelude::*,
     anchor_spl::{
         associated_token::AssociatedToken,
-        token::{transfer, Mint, Token, TokenAccount, Transfer},
+        token_interface::{
+            mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+        },
     },
 };
 
 #[derive(Accounts)]
 #[instruction(params: BuyInvestmentTrustsParams)]
 pub struct BuyInvestmentTrusts<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
     #[account(mut,
-        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        constraint = matches!(real_estate_investment_trust_scheme.offering.status, OfferingStatus::None | OfferingStatus::Succeeded) @ RealEstateInvestmentTrustsError::OfferingInProgress,
+        constraint = real_estate_investment_trust_scheme.non_compliant_epochs == 0 @ RealEstateInvestmentTrustsError::SchemeNonCompliant,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
     )]
     pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // required once the scheme has a jurisdiction policy
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Option<Account<'info, JurisdictionPolicy>>,
     #[account(mut,has_one = owner,
         constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
     )]
     pub investor: Account<'info, Investor>,
-    #[account(mut)]
-    pub sender_tokens: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub recipient_tokens: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub mint_token: Account<'info, Mint>,
+    // init_if_needed creates the holding on the investor's first purchase in this scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Holding::INIT_SPACE,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    // investor's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub sender_tokens: InterfaceAccount<'info, TokenAccount>,
+    // scheme's settlement vault
+    #[account(mut, address = real_estate_investment_trust_scheme.settlement_vault)]
+    pub recipient_tokens: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // investor's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: InterfaceAccount<'info, TokenAccount>,
     // mut makes it changeble (mutable)
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
     pub associate_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BuyInvestmentTrustsParams {
-    pub amount: u32,
+    pub amount: u64, // whole units of investment trusts to buy
 }
 
 pub fn buy_investment_trusts(
@@ -60,54 +113,102 @@ pub fn buy_investment_trusts(
     let token_program = &ctx.accounts.token_program;
     let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
     let investor = &mut ctx.accounts.investor;
-    let unit_cost_of_investment_trusts: u32 =
+    let holding = &mut ctx.accounts.holding;
+    let unit_cost_of_investment_trusts: u64 =
         real_estate_investment_trust_scheme.unit_cost_of_investment_trusts;
     let investor_funds_raised = real_estate_investment_trust_scheme.investor_funds_raised;
-    let total_units_investment_trusts: u32 = investor.total_units_investment_trusts;
-    let available_funds: u32 = investor.available_funds;
-    let decimals = real_estate_investment_trust_scheme.decimals as u64;
     let _amount = params.amount;
 
-    // Get unit_investment_trusts from the product of unit_cost_of_investment_trusts and _amount
-    let unit_investment_trusts = unit_cost_of_investment_trusts
-        .checked_mul(_amount)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    // units are priced at the latest valuation, which must not be stale
+    let clock = Clock::get()?;
+    real_estate_investment_trust_scheme.check_valuation_fresh(
+        ctx.accounts.investment_trusts_configs.max_nav_age,
+        clock.unix_timestamp,
+    )?;
 
-    // Increment total_units_investment_trusts with new unit_investment_trusts
-    investor.total_units_investment_trusts = total_units_investment_trusts
-        .checked_add(unit_investment_trusts)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    investor.check_eligibility(
+        &ctx.accounts.investment_trusts_configs,
+        real_estate_investment_trust_scheme,
+        ctx.accounts.jurisdiction_policy.as_deref(),
+        clock.unix_timestamp,
+    )?;
 
-    // Increment available_funds with new _amount
-    investor.available_funds = available_funds
-        .checked_add(_amount)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    // the investor buys _amount units, paying unit_cost_of_investment_trusts for each one
+    let unit_investment_trusts = _amount;
+    let cost = quote_value(_amount, unit_cost_of_investment_trusts)?;
 
-    // Increment investor_funds_raised with new _amount
+    // Increment investor_funds_raised with the cost paid
     real_estate_investment_trust_scheme.investor_funds_raised = investor_funds_raised
-        .checked_add(_amount)
+        .checked_add(cost)
         .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
 
-    let base: u32 = 10;
-    let exponent = real_estate_investment_trust_scheme.decimals as u32;
+    // Increment the scheme's issued units with new unit_investment_trusts
+    real_estate_investment_trust_scheme.total_units_investment_trusts =
+        real_estate_investment_trust_scheme
+            .total_units_investment_trusts
+            .checked_add(unit_investment_trusts)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
 
-    // lets get the amount in decimal format
-    // 10 ** 9 * 3(base 10, 9 decimals, 3 amount), // 3 amount of token to transfer (in smallest unit i.e 9 decimals)
-    let result = (base).pow(exponent);
-    let _amount = (_amount as u64)
-        .checked_mul(result as u64)
+    // holding
+    holding.initialize(
+        real_estate_investment_trust_scheme.key(),
+        investor.key(),
+        *sender.key,
+        clock.slot,
+    );
+
+    holding.credit(
+        real_estate_investment_trust_scheme,
+        investor,
+        unit_investment_trusts,
+        cost,
+    )?;
+
+    // every purchase restarts the lock-up on the position
+    holding.locked_until = clock
+        .unix_timestamp
+        .checked_add(real_estate_investment_trust_scheme.lock_up_period)
         .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
 
-    transfer(
+    // lets get the amounts in the smallest unit of each mint
+    let cost = quote_to_base_units(cost, ctx.accounts.mint_token.decimals)?;
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    // the investor pays for the units in the scheme's quote mint, covering any
+    // transfer fee so the settlement vault receives the full cost
+    transfer_checked(
         CpiContext::new(
-            token_program.to_account_info(),
-            Transfer {
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
                 from: sender_tokens.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
                 to: recipient_tokens.to_account_info(),
                 authority: sender.to_account_info(),
             },
         ),
-        _amount,
+        with_transfer_fee(&ctx.accounts.mint_token, cost)?,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    // the subscription is settled, issue the units to the investor
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.investor_units.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        units,
     )?;
 
     Ok(())
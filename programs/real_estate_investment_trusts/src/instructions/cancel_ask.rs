@@ -0,0 +1,125 @@
+//! CancelAsk instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::units_to_base_units,
+        state::{
+            holding::Holding, order::Order,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+        token::return_units_with_hook,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{
+            close_account, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct CancelAsk<'info> {
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // closing returns the rent of the ask to the seller
+    #[account(mut, has_one = owner, close = owner,
+        seeds = [
+            b"order",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            order.index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub order: Account<'info, Order>,
+    #[account(mut, address = order.escrow)]
+    pub order_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut,
+        constraint = !holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), order.investor.as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // seller's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: InterfaceAccount<'info, TokenAccount>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associate_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cancel_ask<'info>(ctx: Context<'_, '_, '_, 'info, CancelAsk<'info>>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let order = &mut ctx.accounts.order;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+
+    // units not filled go back to the seller
+    let unit_investment_trusts = order.units;
+    order.units = 0;
+
+    holding.listed_units = holding
+        .listed_units
+        .checked_sub(unit_investment_trusts)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    if unit_investment_trusts > 0 {
+        let units = units_to_base_units(
+            unit_investment_trusts,
+            real_estate_investment_trust_scheme.decimals,
+        )?;
+        let decimals = real_estate_investment_trust_scheme.decimals;
+
+        return_units_with_hook(
+            real_estate_investment_trust_scheme,
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.order_escrow.to_account_info(),
+                    mint: ctx.accounts.unit_mint.to_account_info(),
+                    to: ctx.accounts.investor_units.to_account_info(),
+                    authority: real_estate_investment_trust_scheme.to_account_info(),
+                },
+                signer,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            units,
+            decimals,
+        )?;
+    }
+
+    // the emptied escrow returns its rent to the seller
+    close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.order_escrow.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: real_estate_investment_trust_scheme.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! CheckPayoutCompliance instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            income_epoch::IncomeEpoch,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CheckPayoutCompliance<'info> {
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(mut,
+        constraint = !income_epoch.flagged @ RealEstateInvestmentTrustsError::IncomeEpochAlreadyFlagged,
+        seeds = [
+            b"income-epoch",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            income_epoch.index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub income_epoch: Account<'info, IncomeEpoch>,
+    // any signer may flag a scheme that missed its payout deadline
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn check_payout_compliance(ctx: Context<CheckPayoutCompliance>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let income_epoch = &mut ctx.accounts.income_epoch;
+
+    if Clock::get()?.unix_timestamp <= income_epoch.deadline {
+        return Err(RealEstateInvestmentTrustsError::PayoutDeadlineNotReached.into());
+    }
+
+    if income_epoch.payout_met()? {
+        return Err(RealEstateInvestmentTrustsError::PayoutRatioMet.into());
+    }
+
+    // new subscriptions stay blocked until distributions catch up with the period
+    income_epoch.flagged = true;
+    real_estate_investment_trust_scheme.non_compliant_epochs = real_estate_investment_trust_scheme
+        .non_compliant_epochs
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! ClaimDistribution instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            distribution::Distribution, holding::Holding, investor::Investor,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimDistribution<'info> {
+    #[account(
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(has_one = owner)]
+    pub investor: Account<'info, Investor>,
+    #[account(mut, has_one = owner,
+        constraint = !holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        constraint = !holding.drip @ RealEstateInvestmentTrustsError::DripElected,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    // oldest distribution in the holding's accrued funds, they expire with it
+    #[account(
+        seeds = [
+            b"distribution",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            distribution.index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+    #[account(mut, address = real_estate_investment_trust_scheme.distribution_vault)]
+    pub distribution_vault: InterfaceAccount<'info, TokenAccount>,
+    // investor's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_tokens: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_distribution(ctx: Context<ClaimDistribution>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &ctx.accounts.real_estate_investment_trust_scheme;
+    let holding = &mut ctx.accounts.holding;
+
+    holding.settle_distributions(real_estate_investment_trust_scheme)?;
+
+    let amount = holding.accrued_distributions;
+    if amount == 0 {
+        return Err(RealEstateInvestmentTrustsError::NothingToClaim.into());
+    }
+
+    // funds can no longer be claimed once the oldest distribution they include expires
+    let distribution = &ctx.accounts.distribution;
+    if distribution.index != holding.accrued_from {
+        return Err(RealEstateInvestmentTrustsError::DistributionMismatch.into());
+    }
+    if Clock::get()?.unix_timestamp >= distribution.expires_at {
+        return Err(RealEstateInvestmentTrustsError::DistributionExpired.into());
+    }
+
+    holding.accrued_distributions = 0;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.distribution_vault.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.recipient_tokens.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    Ok(())
+}
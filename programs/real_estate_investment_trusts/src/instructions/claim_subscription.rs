@@ -0,0 +1,136 @@
+//! ClaimSubscription instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::units_to_base_units,
+        state::{
+            holding::Holding, investor::Investor, offering::OfferingStatus,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            subscription::Subscription,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ClaimSubscription<'info> {
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.offering.status == OfferingStatus::Succeeded @ RealEstateInvestmentTrustsError::OfferingNotSucceeded,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(mut, has_one = owner)]
+    pub investor: Account<'info, Investor>,
+    #[account(mut, has_one = owner,
+        constraint = !subscription.claimed @ RealEstateInvestmentTrustsError::SubscriptionAlreadyClaimed,
+        seeds = [b"subscription", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Holding::INIT_SPACE,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    #[account(mut, address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // investor's unit token account, receives units
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: InterfaceAccount<'info, TokenAccount>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_subscription(ctx: Context<ClaimSubscription>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let investor = &mut ctx.accounts.investor;
+    let subscription = &mut ctx.accounts.subscription;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+    let _amount = subscription.amount;
+
+    subscription.claimed = true;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    // Get unit_investment_trusts from the subscribed funds at the offering price
+    let unit_investment_trusts = _amount
+        .checked_div(real_estate_investment_trust_scheme.offering.price)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // Increment investor_funds_raised with new _amount
+    real_estate_investment_trust_scheme.investor_funds_raised = real_estate_investment_trust_scheme
+        .investor_funds_raised
+        .checked_add(_amount)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // Increment the scheme's issued units with new unit_investment_trusts
+    real_estate_investment_trust_scheme.total_units_investment_trusts =
+        real_estate_investment_trust_scheme
+            .total_units_investment_trusts
+            .checked_add(unit_investment_trusts)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // holding
+    let clock = Clock::get()?;
+    holding.initialize(
+        real_estate_investment_trust_scheme.key(),
+        investor.key(),
+        *ctx.accounts.owner.key,
+        clock.slot,
+    );
+
+    holding.credit(
+        real_estate_investment_trust_scheme,
+        investor,
+        unit_investment_trusts,
+        _amount,
+    )?;
+
+    // the lock-up runs from the day units are issued
+    holding.locked_until = clock
+        .unix_timestamp
+        .checked_add(real_estate_investment_trust_scheme.lock_up_period)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.investor_units.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        units,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! CloseRedemptionWindow instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::BASIS_POINTS,
+        state::{
+            configs::InvestmentTrustsConfigs,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            redemption::RedemptionWindow,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CloseRedemptionWindow<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + RedemptionWindow::INIT_SPACE,
+        seeds = [
+            b"redemption-window",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            real_estate_investment_trust_scheme.redemption_window.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub redemption_window: Account<'info, RedemptionWindow>,
+    // any signer may close a window that has run its length
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_redemption_window(ctx: Context<CloseRedemptionWindow>) -> Result<()> {
+    let investment_trusts_configs = &ctx.accounts.investment_trusts_configs;
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let redemption_window = &mut ctx.accounts.redemption_window;
+    let queued = real_estate_investment_trust_scheme.queued_redemption_units;
+
+    if queued == 0 {
+        return Err(RealEstateInvestmentTrustsError::NothingToRedeem.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let window_end = real_estate_investment_trust_scheme
+        .redemption_window_start
+        .checked_add(investment_trusts_configs.redemption_window_length)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    if now < window_end {
+        return Err(RealEstateInvestmentTrustsError::RedemptionWindowOpen.into());
+    }
+
+    // units are redeemed at the latest valuation, which must not be stale
+    real_estate_investment_trust_scheme
+        .check_valuation_fresh(investment_trusts_configs.max_nav_age, now)?;
+    let unit_cost = real_estate_investment_trust_scheme.unit_cost_of_investment_trusts;
+
+    // the gate caps the window at a share of the net asset value, in whole units
+    let units_filled = if investment_trusts_configs.redemption_gate_bps == 0 {
+        queued
+    } else {
+        let gate = (real_estate_investment_trust_scheme.net_asset_value as u128)
+            .checked_mul(investment_trusts_configs.redemption_gate_bps as u128)
+            .and_then(|value| value.checked_div(BASIS_POINTS as u128))
+            .and_then(|value| value.checked_div(unit_cost as u128))
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        u64::try_from(gate).unwrap_or(u64::MAX).min(queued)
+    };
+
+    redemption_window.real_estate_investment_trust_scheme =
+        real_estate_investment_trust_scheme.key();
+    redemption_window.index = real_estate_investment_trust_scheme.redemption_window;
+    redemption_window.units_queued = queued;
+    redemption_window.units_filled = units_filled;
+    redemption_window.units_settled = 0;
+    redemption_window.units_paid = 0;
+    redemption_window.unit_cost = unit_cost;
+    redemption_window.closed_at = now;
+    redemption_window.is_initialized = true;
+
+    // requests not filled carry over to the next window
+    real_estate_investment_trust_scheme.queued_redemption_units = queued - units_filled;
+    real_estate_investment_trust_scheme.redemption_window = real_estate_investment_trust_scheme
+        .redemption_window
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    real_estate_investment_trust_scheme.redemption_window_start = now;
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! ConfigureOffering instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            offering::OfferingStatus,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+#[instruction(params: ConfigureOfferingParams)]
+pub struct ConfigureOffering<'info> {
+    #[account(mut, has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.offering.status == OfferingStatus::None @ RealEstateInvestmentTrustsError::OfferingAlreadyConfigured,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    // subscriptions are held in escrow under the scheme until the offering is finalized
+    #[account(
+        init,
+        payer = owner,
+        token::mint = quote_mint,
+        token::authority = real_estate_investment_trust_scheme,
+        seeds = [b"offering-escrow", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub offering_escrow: InterfaceAccount<'info, TokenAccount>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureOfferingParams {
+    pub soft_cap: u64,   // minimum raise for the offering to succeed
+    pub hard_cap: u64,   // maximum raise accepted
+    pub min_ticket: u64, // minimum subscription per investor
+    pub start_time: i64, // unix timestamp the offering opens
+    pub end_time: i64,   // unix timestamp the offering closes
+}
+
+pub fn configure_offering(
+    ctx: Context<ConfigureOffering>,
+    params: &ConfigureOfferingParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+
+    // the offering runs before the scheme is listed for trading
+    match real_estate_investment_trust_scheme.status {
+        SchemeStatus::Pending | SchemeStatus::UnderReview | SchemeStatus::Approved => {}
+        _ => return Err(RealEstateInvestmentTrustsError::InvalidSchemeStatusTransition.into()),
+    }
+
+    if params.soft_cap == 0 || params.soft_cap > params.hard_cap {
+        return Err(RealEstateInvestmentTrustsError::InvalidOfferingCaps.into());
+    }
+
+    if params.min_ticket > params.hard_cap {
+        return Err(RealEstateInvestmentTrustsError::InvalidOfferingCaps.into());
+    }
+
+    if params.start_time >= params.end_time || params.end_time <= Clock::get()?.unix_timestamp {
+        return Err(RealEstateInvestmentTrustsError::InvalidOfferingWindow.into());
+    }
+
+    // subscriptions are priced and claimed at the unit cost fixed here
+    let price = real_estate_investment_trust_scheme.unit_cost_of_investment_trusts;
+
+    let offering = &mut real_estate_investment_trust_scheme.offering;
+    offering.status = OfferingStatus::Open;
+    offering.soft_cap = params.soft_cap;
+    offering.hard_cap = params.hard_cap;
+    offering.min_ticket = params.min_ticket;
+    offering.start_time = params.start_time;
+    offering.end_time = params.end_time;
+    offering.raised = 0;
+    offering.escrow = ctx.accounts.offering_escrow.key();
+    offering.price = price;
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! ConfigurePool instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::BASIS_POINTS,
+        state::{pool::Pool, real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+#[instruction(params: ConfigurePoolParams)]
+pub struct ConfigurePool<'info> {
+    #[account(has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // init_if_needed creates the pool on its first configuration
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [b"pool", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    // pooled units and quote tokens are held under the scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = unit_mint,
+        token::authority = real_estate_investment_trust_scheme,
+        token::token_program = token_program,
+        seeds = [b"pool-units", pool.key().as_ref()],
+        bump
+    )]
+    pub unit_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = quote_mint,
+        token::authority = real_estate_investment_trust_scheme,
+        token::token_program = quote_token_program,
+        seeds = [b"pool-quote", pool.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigurePoolParams {
+    pub fee_bps: u16,        // swap fee kept in the pool for liquidity providers
+    pub price_band_bps: u16, // largest deviation of the pool price from NAV
+}
+
+pub fn configure_pool(ctx: Context<ConfigurePool>, params: &ConfigurePoolParams) -> Result<()> {
+    msg!("Validate inputs");
+    if params.fee_bps >= BASIS_POINTS || params.price_band_bps > BASIS_POINTS {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
+    }
+
+    let pool = &mut ctx.accounts.pool;
+
+    if !pool.is_initialized {
+        pool.real_estate_investment_trust_scheme =
+            ctx.accounts.real_estate_investment_trust_scheme.key();
+        pool.unit_vault = ctx.accounts.unit_vault.key();
+        pool.quote_vault = ctx.accounts.quote_vault.key();
+        pool.is_initialized = true;
+    }
+
+    pool.fee_bps = params.fee_bps;
+    pool.price_band_bps = params.price_band_bps;
+
+    Ok(())
+}
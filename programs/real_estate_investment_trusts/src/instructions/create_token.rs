@@ -4,74 +4,161 @@ use {
     crate::{
         error::RealEstateInvestmentTrustsError,
         state::real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        token::update_token_metadata_fields,
     },
-    anchor_lang::{prelude::*, system_program},
+    anchor_lang::{prelude::*, solana_program::program_pack::Pack, system_program},
     anchor_spl::{
-        associated_token,
-        associated_token::AssociatedToken,
-        token::{initialize_mint, mint_to, InitializeMint, Mint, MintTo, Token, TokenAccount},
+        token::spl_token,
+        token_2022::{
+            spl_token_2022::{self, extension::ExtensionType},
+            Token2022,
+        },
+        token_2022_extensions::{
+            metadata_pointer_initialize, permanent_delegate_initialize,
+            spl_pod::optional_keys::OptionalNonZeroPubkey,
+            spl_token_metadata_interface::state::{Field, TokenMetadata},
+            token_metadata_initialize, transfer_hook_initialize, MetadataPointerInitialize,
+            PermanentDelegateInitialize, TokenMetadataInitialize, TransferHookInitialize,
+        },
+        token_interface::{initialize_mint, InitializeMint, TokenInterface},
     },
 };
 
 #[derive(Accounts)]
-#[instruction(params: CreateTokenParams)]
 pub struct CreateToken<'info> {
-    #[account(mut,
-        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized
+    #[account(mut, has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.unit_mint == Pubkey::default() @ RealEstateInvestmentTrustsError::AccountAlreadyInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
     )]
     pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
-    #[account(mut)]
-    ///CHECK:
-    pub token_account: AccountInfo<'info>,
-    #[account(mut)]
-    pub mint_token: Signer<'info>,
+    // unit mint is a program address derived from the scheme
+    #[account(mut,
+        seeds = [b"unit-mint", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    ///CHECK: created and initialized below
+    pub mint_token: UncheckedAccount<'info>,
     // mut makes it changeble (mutable)
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub token_program: Program<'info, Token>,
-    pub associate_token_program: Program<'info, AssociatedToken>,
+    // Token-2022 creates the mint with this program as its transfer hook, the
+    // scheme as permanent delegate and token metadata from the issuer details
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct CreateTokenParams {
-    pub amount: u32,
-}
+pub fn create_token(ctx: Context<CreateToken>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let decimals = real_estate_investment_trust_scheme.decimals;
+    let scheme_key = real_estate_investment_trust_scheme.key();
 
-pub fn create_token(ctx: Context<CreateToken>, params: &CreateTokenParams) -> Result<()> {
-    msg!("Validate inputs");
-    if params.amount == 0 {
-        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
-    }
+    let mint_seeds = &[b"unit-mint", scheme_key.as_ref(), &[ctx.bumps.mint_token]];
+    let signer = &[&mint_seeds[..]];
 
-    let real_estate_investment_trust_scheme = &ctx.accounts.real_estate_investment_trust_scheme;
-    let decimals = real_estate_investment_trust_scheme.decimals;
-    let _amount = params.amount;
+    // a Token-2022 mint carries the transfer hook extension, so every unit
+    // transfer is checked by this program, the permanent delegate extension, so
+    // compliance can force transfer units, and token metadata so wallets can
+    // show the units
+    let token_2022 = ctx.accounts.token_program.key() == Token2022::id();
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(scheme_key))?,
+        mint: ctx.accounts.mint_token.key(),
+        name: real_estate_investment_trust_scheme.issuer.name.to_string(),
+        symbol: real_estate_investment_trust_scheme
+            .issuer
+            .symbol()
+            .to_string(),
+        uri: String::new(),
+        additional_metadata: real_estate_investment_trust_scheme
+            .issuer
+            .additional_metadata(),
+    };
 
-    let base: u32 = 10;
-    let exponent = real_estate_investment_trust_scheme.decimals as u32;
+    // the metadata is appended to the mint after it is initialized, so the mint
+    // is only sized for the fixed extensions but funded for the metadata too
+    let (space, metadata_space) = if token_2022 {
+        (
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+                ExtensionType::TransferHook,
+                ExtensionType::MetadataPointer,
+                ExtensionType::PermanentDelegate,
+            ])?,
+            token_metadata.tlv_size_of()?,
+        )
+    } else {
+        (spl_token::state::Mint::LEN, 0)
+    };
 
-    // lets get the amount in decimal format
-    // 10 ** 9 * 3(base 10, 9 decimals, 3 amount), // 3 amount of token to transfer (in smallest unit i.e 9 decimals)
-    let result = (base).pow(exponent);
-    let _amount = (_amount as u64)
-        .checked_mul(result as u64)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    // mint account is funded to be rent exempt
+    let lamports = ctx.accounts.rent.minimum_balance(
+        space
+            .checked_add(metadata_space)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?,
+    );
 
     system_program::create_account(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.system_program.to_account_info(),
             system_program::CreateAccount {
                 from: ctx.accounts.owner.to_account_info(),
                 to: ctx.accounts.mint_token.to_account_info(),
             },
+            signer,
         ),
-        10_000_000,
-        82,
+        lamports,
+        space as u64,
         ctx.accounts.token_program.key,
     )?;
 
+    // extensions are initialized before the mint itself, the scheme can
+    // repoint the hook and this program checks the transfers, the metadata
+    // lives in the mint itself and the scheme is the permanent delegate
+    if token_2022 {
+        transfer_hook_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferHookInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_token.to_account_info(),
+                },
+            ),
+            Some(scheme_key),
+            Some(crate::ID),
+        )?;
+
+        metadata_pointer_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MetadataPointerInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_token.to_account_info(),
+                },
+            ),
+            Some(scheme_key),
+            Some(ctx.accounts.mint_token.key()),
+        )?;
+
+        permanent_delegate_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                PermanentDelegateInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_token.to_account_info(),
+                },
+            ),
+            &scheme_key,
+        )?;
+    }
+
+    // the scheme address is both mint and freeze authority, so units are only
+    // issued by the program when a subscription settles
     initialize_mint(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -81,33 +168,53 @@ pub fn create_token(ctx: Context<CreateToken>, params: &CreateTokenParams) -> Re
             },
         ),
         decimals,
-        ctx.accounts.owner.key,
-        Some(ctx.accounts.owner.key),
+        &scheme_key,
+        Some(&scheme_key),
     )?;
 
-    associated_token::create(CpiContext::new(
-        ctx.accounts.associate_token_program.to_account_info(),
-        associated_token::Create {
-            payer: ctx.accounts.owner.to_account_info(),
-            associated_token: ctx.accounts.token_account.to_account_info(),
-            authority: ctx.accounts.owner.to_account_info(),
-            mint: ctx.accounts.mint_token.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    ))?;
+    // the scheme signs as mint authority and keeps the metadata update authority
+    if token_2022 {
+        let scheme_signer = real_estate_investment_trust_scheme.signer();
+        let seeds = scheme_signer.seeds();
+        let signer = &[&seeds[..]];
 
-    mint_to(
-        CpiContext::new(
-            ctx.accounts.token_account.to_account_info(),
-            MintTo {
-                authority: ctx.accounts.owner.to_account_info(),
-                mint: ctx.accounts.mint_token.to_account_info(),
-                to: ctx.accounts.token_account.to_account_info(),
-            },
-        ),
-        _amount,
-    )?;
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint_token.to_account_info(),
+                    update_authority: real_estate_investment_trust_scheme.to_account_info(),
+                    mint_authority: real_estate_investment_trust_scheme.to_account_info(),
+                    mint: ctx.accounts.mint_token.to_account_info(),
+                },
+                signer,
+            ),
+            token_metadata.name,
+            token_metadata.symbol,
+            token_metadata.uri,
+        )?;
+
+        update_token_metadata_fields(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint_token.to_account_info(),
+            &real_estate_investment_trust_scheme.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            token_metadata
+                .additional_metadata
+                .into_iter()
+                .map(|(key, value)| (Field::Key(key), value))
+                .collect(),
+            signer,
+        )?;
+    }
+
+    // bind the unit mint to the scheme
+    real_estate_investment_trust_scheme.unit_mint = ctx.accounts.mint_token.key();
+    real_estate_investment_trust_scheme.unit_transfer_hook = token_2022;
+    real_estate_investment_trust_scheme.unit_metadata = token_2022;
+    real_estate_investment_trust_scheme.unit_permanent_delegate = token_2022;
 
     Ok(())
 }
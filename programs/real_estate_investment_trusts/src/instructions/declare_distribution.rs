@@ -0,0 +1,194 @@
+//! DeclareDistribution instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{quote_to_base_units, REWARD_SCALE},
+        state::{
+            distribution::Distribution, income_epoch::IncomeEpoch,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+        token::with_transfer_fee,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: DeclareDistributionParams)]
+pub struct DeclareDistribution<'info> {
+    #[account(mut, has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Distribution::INIT_SPACE,
+        seeds = [
+            b"distribution",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            real_estate_investment_trust_scheme.distribution_count.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+    // income period the distribution pays out
+    #[account(mut, has_one = real_estate_investment_trust_scheme @ RealEstateInvestmentTrustsError::IncomeEpochMismatch)]
+    pub income_epoch: Account<'info, IncomeEpoch>,
+    // declared distributions are held under the scheme until holders claim them
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = mint_token,
+        token::authority = real_estate_investment_trust_scheme,
+        seeds = [b"distribution-vault", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub distribution_vault: InterfaceAccount<'info, TokenAccount>,
+    // scheme manager's quote token account funding the distribution
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub sender_tokens: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DeclareDistributionParams {
+    pub amount: u64,       // funds to distribute, fixed-point quote value
+    pub claim_period: i64, // seconds holders have to claim before funds can be swept
+}
+
+// only income REITs pay out rental income
+const INCOME_REIT: u8 = 2;
+
+pub fn declare_distribution(
+    ctx: Context<DeclareDistribution>,
+    params: &DeclareDistributionParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.amount == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    if params.claim_period <= 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
+    }
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let distribution = &mut ctx.accounts.distribution;
+
+    if real_estate_investment_trust_scheme.issuer.type_of_reit != INCOME_REIT {
+        return Err(RealEstateInvestmentTrustsError::NotIncomeReit.into());
+    }
+
+    match real_estate_investment_trust_scheme.status {
+        SchemeStatus::Listed | SchemeStatus::Suspended => {}
+        _ => return Err(RealEstateInvestmentTrustsError::SchemeNotListed.into()),
+    }
+
+    // holders on record at declaration share the distribution pro rata,
+    // units in the liquidity pool belong to no holding and earn nothing
+    let total_units = real_estate_investment_trust_scheme
+        .total_units_investment_trusts
+        .checked_sub(real_estate_investment_trust_scheme.pooled_units)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    if total_units == 0 {
+        return Err(RealEstateInvestmentTrustsError::NoUnitsIssued.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let expires_at = now
+        .checked_add(params.claim_period)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // expiries never decrease, so distributions expire and are swept in order
+    if expires_at < real_estate_investment_trust_scheme.last_distribution_expiry {
+        return Err(RealEstateInvestmentTrustsError::DistributionExpiryNotMonotonic.into());
+    }
+
+    let amount = quote_to_base_units(params.amount, ctx.accounts.mint_token.decimals)?;
+
+    let increment = (amount as u128)
+        .checked_mul(REWARD_SCALE)
+        .and_then(|value| value.checked_div(total_units as u128))
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    real_estate_investment_trust_scheme.reward_per_unit = real_estate_investment_trust_scheme
+        .reward_per_unit
+        .checked_add(increment)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // distribution
+    distribution.real_estate_investment_trust_scheme = real_estate_investment_trust_scheme.key();
+    distribution.index = real_estate_investment_trust_scheme.distribution_count;
+    distribution.amount = amount;
+    distribution.record_date = now;
+    distribution.expires_at = expires_at;
+    distribution.reward_per_unit = real_estate_investment_trust_scheme.reward_per_unit;
+    distribution.is_initialized = true;
+
+    real_estate_investment_trust_scheme.distribution_count = real_estate_investment_trust_scheme
+        .distribution_count
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    real_estate_investment_trust_scheme.distribution_vault = ctx.accounts.distribution_vault.key();
+    real_estate_investment_trust_scheme.last_distribution_expiry = expires_at;
+    real_estate_investment_trust_scheme.unswept_distribution_amount =
+        real_estate_investment_trust_scheme
+            .unswept_distribution_amount
+            .checked_add(amount)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // income epoch
+    let income_epoch = &mut ctx.accounts.income_epoch;
+    income_epoch.distributed = income_epoch
+        .distributed
+        .checked_add(amount)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // a flagged period that catches up no longer counts against the scheme
+    if income_epoch.flagged && income_epoch.payout_met()? {
+        income_epoch.flagged = false;
+        real_estate_investment_trust_scheme.non_compliant_epochs =
+            real_estate_investment_trust_scheme
+                .non_compliant_epochs
+                .checked_sub(1)
+                .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    }
+
+    // the promoter covers any transfer fee so the vault holds the full distribution
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.sender_tokens.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.distribution_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        with_transfer_fee(&ctx.accounts.mint_token, amount)?,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//! DeregisterIssuer instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            configs::InvestmentTrustsConfigs, issuer_registry_entry::IssuerRegistryEntry,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme, role::Role,
+            scheme_status::SchemeStatus,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct DeregisterIssuer<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::SchemeApprover) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // only delisted schemes leave the registry
+    #[account(
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Delisted @ RealEstateInvestmentTrustsError::SchemeNotDelisted
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // close returns the rent to the promoter who paid for the entry
+    #[account(mut,
+        close = promoter,
+        seeds = [b"issuer-registry", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub issuer_registry_entry: Account<'info, IssuerRegistryEntry>,
+    #[account(mut, address = real_estate_investment_trust_scheme.owner)]
+    pub promoter: SystemAccount<'info>,
+    // scheme approver
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deregister_issuer(ctx: Context<DeregisterIssuer>) -> Result<()> {
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    // investment_trusts_configs
+    investment_trusts_configs.issuer_count = investment_trusts_configs
+        .issuer_count
+        .checked_sub(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    Ok(())
+}
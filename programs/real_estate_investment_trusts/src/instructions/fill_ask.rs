@@ -0,0 +1,240 @@
+//! FillAsk instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{quote_to_base_units, quote_value, units_to_base_units},
+        state::{
+            configs::InvestmentTrustsConfigs, holding::Holding, investor::Investor,
+            jurisdiction_policy::JurisdictionPolicy, order::Order,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+        token::{transfer_checked_with_hook, with_transfer_fee},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: FillAskParams)]
+pub struct FillAsk<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // required once the scheme has a jurisdiction policy
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Option<Account<'info, JurisdictionPolicy>>,
+    #[account(mut,
+        constraint = order.owner != owner.key() @ RealEstateInvestmentTrustsError::SelfTrade,
+        seeds = [
+            b"order",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            order.index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub order: Account<'info, Order>,
+    #[account(mut, address = order.escrow)]
+    pub order_escrow: InterfaceAccount<'info, TokenAccount>,
+    // seller, must still be eligible to trade
+    #[account(mut, address = order.investor,
+        constraint = seller_investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub seller_investor: Box<Account<'info, Investor>>,
+    #[account(mut,
+        constraint = !seller_holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), seller_investor.key().as_ref()],
+        bump
+    )]
+    pub seller_holding: Box<Account<'info, Holding>>,
+    // seller's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = order.owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub recipient_tokens: InterfaceAccount<'info, TokenAccount>,
+    // buyer
+    #[account(mut, has_one = owner,
+        constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub investor: Box<Account<'info, Investor>>,
+    // init_if_needed creates the holding on the buyer's first purchase in this scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Holding::INIT_SPACE,
+        constraint = !holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Box<Account<'info, Holding>>,
+    // buyer's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub sender_tokens: InterfaceAccount<'info, TokenAccount>,
+    // buyer's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associate_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FillAskParams {
+    pub units: u64, // whole units of investment trusts to take from the ask
+}
+
+pub fn fill_ask<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillAsk<'info>>,
+    params: &FillAskParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.units == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let order = &mut ctx.accounts.order;
+    let seller_investor = &mut ctx.accounts.seller_investor;
+    let seller_holding = &mut ctx.accounts.seller_holding;
+    let investor = &mut ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+    let unit_investment_trusts = params.units;
+
+    // both sides of the trade must be eligible for the scheme
+    let clock = Clock::get()?;
+    for party in [&investor, &seller_investor] {
+        party.check_eligibility(
+            &ctx.accounts.investment_trusts_configs,
+            real_estate_investment_trust_scheme,
+            ctx.accounts.jurisdiction_policy.as_deref(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // a partial fill leaves the rest of the ask open
+    if unit_investment_trusts > order.units {
+        return Err(RealEstateInvestmentTrustsError::InsufficientOrderUnits.into());
+    }
+
+    order.units -= unit_investment_trusts;
+
+    // the buyer pays the ask price for each unit taken
+    let cost = quote_value(unit_investment_trusts, order.price)?;
+
+    // seller holding, the funds released are the cost paid for the units sold
+    seller_holding.listed_units = seller_holding
+        .listed_units
+        .checked_sub(unit_investment_trusts)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    seller_holding.debit(
+        real_estate_investment_trust_scheme,
+        seller_investor,
+        unit_investment_trusts,
+    )?;
+
+    // buyer holding, units change hands and the scheme's issued units stay the same
+    holding.initialize(
+        real_estate_investment_trust_scheme.key(),
+        investor.key(),
+        *ctx.accounts.owner.key,
+        clock.slot,
+    );
+    holding.credit(
+        real_estate_investment_trust_scheme,
+        investor,
+        unit_investment_trusts,
+        cost,
+    )?;
+
+    // every purchase restarts the lock-up on the position
+    holding.locked_until = clock
+        .unix_timestamp
+        .checked_add(real_estate_investment_trust_scheme.lock_up_period)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // lets get the amounts in the smallest unit of each mint
+    let cost = quote_to_base_units(cost, ctx.accounts.mint_token.decimals)?;
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    // the buyer pays the seller in the scheme's quote mint, covering any transfer fee
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.sender_tokens.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.recipient_tokens.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        with_transfer_fee(&ctx.accounts.mint_token, cost)?,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    // the units are released from escrow to the buyer
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.order_escrow.to_account_info(),
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.investor_units.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        units,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! FinalizeOffering instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            offering::OfferingStatus,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+pub struct FinalizeOffering<'info> {
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.offering.status == OfferingStatus::Open @ RealEstateInvestmentTrustsError::OfferingNotOpen,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(mut, address = real_estate_investment_trust_scheme.offering.escrow)]
+    pub offering_escrow: InterfaceAccount<'info, TokenAccount>,
+    // scheme's settlement vault
+    #[account(mut, address = real_estate_investment_trust_scheme.settlement_vault)]
+    pub settlement_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // any signer may finalize once the offering has closed
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn finalize_offering(ctx: Context<FinalizeOffering>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let offering = &real_estate_investment_trust_scheme.offering;
+
+    // the offering closes at its end time, or early once the hard cap is reached
+    let now = Clock::get()?.unix_timestamp;
+    if now < offering.end_time && offering.raised < offering.hard_cap {
+        return Err(RealEstateInvestmentTrustsError::OfferingNotClosed.into());
+    }
+
+    if offering.raised < offering.soft_cap {
+        // subscribers reclaim their funds from escrow
+        real_estate_investment_trust_scheme.offering.status = OfferingStatus::Failed;
+        return Ok(());
+    }
+
+    real_estate_investment_trust_scheme.offering.status = OfferingStatus::Succeeded;
+
+    // the raise moves from escrow into the scheme's treasury
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.offering_escrow.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.settlement_vault.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        ctx.accounts.offering_escrow.amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! ForceTransfer instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        events::UnitsForceTransferred,
+        math::units_to_base_units,
+        state::{
+            compliance_reason::ComplianceReason,
+            configs::InvestmentTrustsConfigs,
+            holding::{move_units, Holding},
+            investor::Investor,
+            jurisdiction_policy::JurisdictionPolicy,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            role::Role,
+        },
+        token::transfer_checked_with_hook,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        freeze_account, thaw_account, FreezeAccount, Mint, ThawAccount, TokenAccount,
+        TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: ForceTransferParams)]
+pub struct ForceTransfer<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::ComplianceOfficer) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.unit_permanent_delegate @ RealEstateInvestmentTrustsError::ForcedTransferNotEnabled,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Box<Account<'info, RealEstateInvestmentTrustScheme>>,
+    // required once the scheme has a jurisdiction policy
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Option<Account<'info, JurisdictionPolicy>>,
+    // holder the units are taken from, need not be eligible anymore
+    #[account(mut)]
+    pub from_investor: Box<Account<'info, Investor>>,
+    #[account(mut,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), from_investor.key().as_ref()],
+        bump
+    )]
+    pub from_holding: Box<Account<'info, Holding>>,
+    #[account(mut,
+        token::mint = unit_mint,
+        token::authority = from_investor.owner,
+        token::token_program = token_program
+    )]
+    pub from_units: InterfaceAccount<'info, TokenAccount>,
+    // units may only be moved to another eligible investor
+    #[account(mut,
+        constraint = to_investor.key() != from_investor.key() @ RealEstateInvestmentTrustsError::InvalidForcedTransferRecipient,
+        constraint = to_investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub to_investor: Box<Account<'info, Investor>>,
+    // init_if_needed creates the holding when the recipient is new to the scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Holding::INIT_SPACE,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), to_investor.key().as_ref()],
+        bump
+    )]
+    pub to_holding: Box<Account<'info, Holding>>,
+    #[account(mut,
+        token::mint = unit_mint,
+        token::authority = to_investor.owner,
+        token::token_program = token_program
+    )]
+    pub to_units: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // compliance officer
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ForceTransferParams {
+    pub units: u64,               // whole units of investment trusts to move
+    pub reason: ComplianceReason, // recorded on both holdings and in the event
+}
+
+pub fn force_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, ForceTransfer<'info>>,
+    params: &ForceTransferParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.units == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+    if params.reason == ComplianceReason::None {
+        return Err(RealEstateInvestmentTrustsError::InvalidComplianceReason.into());
+    }
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let from_investor = &mut ctx.accounts.from_investor;
+    let from_holding = &mut ctx.accounts.from_holding;
+    let to_investor = &mut ctx.accounts.to_investor;
+    let to_holding = &mut ctx.accounts.to_holding;
+    let token_program = &ctx.accounts.token_program;
+    let unit_investment_trusts = params.units;
+
+    // the recipient must be eligible for this scheme
+    let clock = Clock::get()?;
+    to_investor.check_eligibility(
+        &ctx.accounts.investment_trusts_configs,
+        real_estate_investment_trust_scheme,
+        ctx.accounts.jurisdiction_policy.as_deref(),
+        clock.unix_timestamp,
+    )?;
+
+    // units queued for redemption or listed in asks sit in scheme escrows,
+    // the lock-up does not hold back a compliance order
+    if unit_investment_trusts > from_holding.free_units()? {
+        return Err(RealEstateInvestmentTrustsError::InsufficientUnits.into());
+    }
+
+    to_holding.initialize(
+        real_estate_investment_trust_scheme.key(),
+        to_investor.key(),
+        to_investor.owner,
+        clock.slot,
+    );
+    move_units(
+        real_estate_investment_trust_scheme,
+        from_holding,
+        from_investor,
+        to_holding,
+        to_investor,
+        unit_investment_trusts,
+    )?;
+    from_holding.compliance_reason = params.reason;
+    to_holding.compliance_reason = params.reason;
+
+    // the units keep the lock-up they were under
+    to_holding.locked_until = to_holding.locked_until.max(from_holding.locked_until);
+
+    // lets get the amount in the smallest unit of the unit mint
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    // the scheme signs as permanent delegate and as freeze authority
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    // a frozen account is thawed for the transfer and frozen again after it
+    if from_holding.frozen {
+        thaw_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.from_units.to_account_info(),
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ))?;
+    }
+
+    // remaining accounts carry the transfer hook accounts of the unit mint
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.from_units.to_account_info(),
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.to_units.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        units,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    if from_holding.frozen {
+        freeze_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.from_units.to_account_info(),
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ))?;
+    }
+
+    emit!(UnitsForceTransferred {
+        real_estate_investment_trust_scheme: real_estate_investment_trust_scheme.key(),
+        from_holding: from_holding.key(),
+        to_holding: to_holding.key(),
+        units: unit_investment_trusts,
+        reason: params.reason,
+        officer: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
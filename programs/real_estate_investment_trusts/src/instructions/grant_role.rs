@@ -0,0 +1,57 @@
+//! GrantRole instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            configs::{InvestmentTrustsConfigs, MAX_ROLE_ASSIGNMENTS},
+            role::{Role, RoleAssignment},
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: GrantRoleParams)]
+pub struct GrantRole<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Admin) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GrantRoleParams {
+    pub role: Role,        // role to grant
+    pub authority: Pubkey, // publickey receiving the role
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, params: &GrantRoleParams) -> Result<()> {
+    msg!("Validate inputs");
+    // the admin role is moved with transfer_role, never granted alongside the current admin
+    if params.role == Role::Admin {
+        return Err(RealEstateInvestmentTrustsError::InvalidRole.into());
+    }
+
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    if investment_trusts_configs.has_role(&params.authority, params.role) {
+        return Err(RealEstateInvestmentTrustsError::RoleAlreadyGranted.into());
+    }
+
+    if investment_trusts_configs.roles.len() >= MAX_ROLE_ASSIGNMENTS {
+        return Err(RealEstateInvestmentTrustsError::RoleRegistryFull.into());
+    }
+
+    investment_trusts_configs.roles.push(RoleAssignment {
+        role: params.role,
+        authority: params.authority,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 //! Init instruction handler
 
 use {
-    crate::{error::RealEstateInvestmentTrustsError, state::configs::InvestmentTrustsConfigs},
+    crate::{
+        error::RealEstateInvestmentTrustsError, program::RealEstateInvestmentTrusts,
+        state::configs::InvestmentTrustsConfigs,
+    },
     anchor_lang::prelude::*,
 };
 
@@ -19,6 +22,11 @@ pub struct Init<'info> {
         bump
     )]
     pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ RealEstateInvestmentTrustsError::Unauthorized)]
+    pub program: Program<'info, RealEstateInvestmentTrusts>,
+    // only the program's upgrade authority may initialize it
+    #[account(constraint = program_data.upgrade_authority_address == Some(owner.key()) @ RealEstateInvestmentTrustsError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
     // mut makes it changeble (mutable)
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -36,7 +44,10 @@ pub fn init(ctx: Context<Init>, _params: &InitParams) -> Result<()> {
     let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
 
     // investment trusts configs
+    // the upgrade authority that initializes the program becomes the protocol admin
+    investment_trusts_configs.admin = *ctx.accounts.owner.key;
     investment_trusts_configs.is_initialized = true;
+    investment_trusts_configs.version = InvestmentTrustsConfigs::VERSION;
 
     Ok(())
 }
@@ -0,0 +1,122 @@
+//! InitializeExtraAccountMetaList instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+    spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+    },
+    spl_transfer_hook_interface::instruction::ExecuteInstruction,
+};
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.unit_transfer_hook @ RealEstateInvestmentTrustsError::TransferHookNotEnabled,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(
+        address = real_estate_investment_trust_scheme.unit_mint @ RealEstateInvestmentTrustsError::UnitMintMismatch,
+    )]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // validation account the token program reads to resolve the hook's accounts
+    #[account(
+        init,
+        payer = owner,
+        space = ExtraAccountMetaList::size_of(
+            extra_account_metas(&real_estate_investment_trust_scheme.key())?.len()
+        )?,
+        seeds = [b"extra-account-metas", mint_token.key().as_ref()],
+        bump
+    )]
+    ///CHECK: initialized below with the extra account metas
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// accounts the transfer hook needs beyond the transfer itself, in the order of
+// TransferHook: the scheme, the configs, the scheme's jurisdiction policy and
+// the investor accounts of both token account owners
+pub fn extra_account_metas(scheme: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // index 5, the scheme is fixed per unit mint
+        ExtraAccountMeta::new_with_pubkey(scheme, false, false)?,
+        // index 6
+        ExtraAccountMeta::new_with_seeds(
+            &[Seed::Literal {
+                bytes: b"investment-trusts-configs".to_vec(),
+            }],
+            false,
+            false,
+        )?,
+        // index 7, may not exist if the scheme has no policy
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: b"jurisdiction-policy".to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+            ],
+            false,
+            false,
+        )?,
+        // index 8, from the owner field of the source token account
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: b"investor".to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: 0,
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // index 9, from the owner field of the destination token account
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: b"investor".to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: 2,
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+pub fn initialize_extra_account_meta_list(
+    ctx: Context<InitializeExtraAccountMetaList>,
+) -> Result<()> {
+    let account_metas =
+        extra_account_metas(&ctx.accounts.real_estate_investment_trust_scheme.key())?;
+
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+        &account_metas,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,342 @@
+//! MigrateAccount instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::PRICE_SCALE,
+        state::{
+            compliance_reason::ComplianceReason,
+            configs::{InvestmentTrustsConfigs, MAX_SANCTIONED_COUNTRIES},
+            deposit_base::DepositBase,
+            holding::Holding,
+            investor::Investor,
+            legacy::{
+                DepositBaseV1, HoldingV1, InvestmentTrustsConfigsV1, InvestorV0, InvestorV1,
+                RealEstateInvestmentTrustSchemeV1, SubscriptionV0,
+            },
+            offering::Offering,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            role::Role,
+            subscription::Subscription,
+        },
+    },
+    anchor_lang::{
+        prelude::*,
+        system_program::{transfer, Transfer},
+        Discriminator,
+    },
+};
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Admin) @ RealEstateInvestmentTrustsError::Unauthorized,
+        seeds = [b"investment-trusts-configs"],
+        bump
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    /// CHECK: program owned account, its layout is checked against the discriminator in the handler
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    // admin pays the rent for the larger layout
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// upgrades an account created under an earlier layout to the current one in place,
+// the layout is recognised from the discriminator and the account size
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+
+    let (space, migrated) = {
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 {
+            return Err(RealEstateInvestmentTrustsError::InvalidAccountLayout.into());
+        }
+
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&data[..8]);
+
+        if discriminator == InvestmentTrustsConfigs::DISCRIMINATOR {
+            (
+                8 + InvestmentTrustsConfigs::INIT_SPACE,
+                migrate_investment_trusts_configs(&data)?,
+            )
+        } else if discriminator == Investor::DISCRIMINATOR {
+            (8 + Investor::INIT_SPACE, migrate_investor(&data)?)
+        } else if discriminator == RealEstateInvestmentTrustScheme::DISCRIMINATOR {
+            (
+                8 + RealEstateInvestmentTrustScheme::INIT_SPACE,
+                migrate_real_estate_investment_trust_scheme(&data)?,
+            )
+        } else if discriminator == DepositBase::DISCRIMINATOR {
+            (8 + DepositBase::INIT_SPACE, migrate_deposit_base(&data)?)
+        } else if discriminator == Holding::DISCRIMINATOR {
+            (8 + Holding::INIT_SPACE, migrate_holding(&data)?)
+        } else if discriminator == Subscription::DISCRIMINATOR {
+            (8 + Subscription::INIT_SPACE, migrate_subscription(&data)?)
+        } else {
+            return Err(RealEstateInvestmentTrustsError::InvalidAccountLayout.into());
+        }
+    };
+
+    // top up rent for the new size before growing the account
+    let minimum_balance = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+    if minimum_balance > lamports {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            minimum_balance
+                .checked_sub(lamports)
+                .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?,
+        )?;
+    }
+
+    account.realloc(space, false)?;
+
+    // rewrite everything after the discriminator in the current layout
+    let mut data = account.try_borrow_mut_data()?;
+    data[8..].fill(0);
+    data[8..8 + migrated.len()].copy_from_slice(&migrated);
+
+    Ok(())
+}
+
+// size of the account data after the discriminator
+fn layout_space(data: &[u8]) -> usize {
+    data.len() - 8
+}
+
+fn read_layout<T: AnchorDeserialize>(data: &[u8]) -> Result<T> {
+    T::deserialize(&mut &data[8..])
+        .map_err(|_| RealEstateInvestmentTrustsError::InvalidAccountLayout.into())
+}
+
+// an account of the current size needs no migration, any other size is not ours
+fn unknown_layout(data: &[u8], current_space: usize) -> Error {
+    if layout_space(data) == current_space {
+        RealEstateInvestmentTrustsError::AccountAlreadyMigrated.into()
+    } else {
+        RealEstateInvestmentTrustsError::InvalidAccountLayout.into()
+    }
+}
+
+// whole quote tokens to a fixed-point quote value
+fn to_quote_value(amount: u32) -> Result<u64> {
+    (amount as u64)
+        .checked_mul(PRICE_SCALE)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+}
+
+fn migrate_investment_trusts_configs(data: &[u8]) -> Result<Vec<u8>> {
+    if layout_space(data) != InvestmentTrustsConfigsV1::INIT_SPACE {
+        return Err(unknown_layout(data, InvestmentTrustsConfigs::INIT_SPACE));
+    }
+    let v1: InvestmentTrustsConfigsV1 = read_layout(data)?;
+
+    let investment_trusts_configs = InvestmentTrustsConfigs {
+        admin: v1.admin,
+        issuer_count: v1.issuer_count,
+        roles: v1.roles,
+        paused: v1.paused,
+        is_initialized: v1.is_initialized,
+        version: InvestmentTrustsConfigs::VERSION,
+        max_nav_age: 0,
+        min_payout_ratio_bps: 0,
+        payout_grace_period: 0,
+        redemption_window_length: 0,
+        redemption_gate_bps: 0,
+        sanctioned_countries: [[0; 3]; MAX_SANCTIONED_COUNTRIES],
+        sanctioned_count: 0,
+        pending_admin: Pubkey::default(),
+        reserved: [0; 19],
+    };
+
+    Ok(investment_trusts_configs.try_to_vec()?)
+}
+
+fn migrate_investor(data: &[u8]) -> Result<Vec<u8>> {
+    let space = layout_space(data);
+    let v1 = if space == InvestorV1::INIT_SPACE {
+        read_layout(data)?
+    } else if space == InvestorV0::INIT_SPACE {
+        upgrade_investor_v0(read_layout(data)?)?
+    } else {
+        return Err(unknown_layout(data, Investor::INIT_SPACE));
+    };
+
+    let investor = Investor {
+        owner: v1.owner,
+        full_names: v1.full_names,
+        country: v1.country,
+        active: v1.active,
+        total_units_investment_trusts: v1.total_units_investment_trusts,
+        available_funds: v1.available_funds,
+        version: Investor::VERSION,
+        kyc_level: 0,
+        kyc_expires_at: 0,
+        accredited: false,
+        dossier_hash: [0; 32],
+        attested_at: 0,
+        reserved: [0; 78],
+    };
+
+    Ok(investor.try_to_vec()?)
+}
+
+fn upgrade_investor_v0(v0: InvestorV0) -> Result<InvestorV1> {
+    Ok(InvestorV1 {
+        owner: v0.owner,
+        full_names: v0.full_names,
+        country: v0.country,
+        active: v0.active,
+        total_units_investment_trusts: v0.total_units_investment_trusts as u64,
+        available_funds: to_quote_value(v0.available_funds)?,
+    })
+}
+
+fn migrate_real_estate_investment_trust_scheme(data: &[u8]) -> Result<Vec<u8>> {
+    if layout_space(data) != RealEstateInvestmentTrustSchemeV1::INIT_SPACE {
+        return Err(unknown_layout(
+            data,
+            RealEstateInvestmentTrustScheme::INIT_SPACE,
+        ));
+    }
+    let v1: RealEstateInvestmentTrustSchemeV1 = read_layout(data)?;
+
+    let real_estate_investment_trust_scheme = RealEstateInvestmentTrustScheme {
+        owner: v1.owner,
+        scheme_id: v1.scheme_id,
+        bump: v1.bump,
+        issuer: v1.issuer,
+        country: v1.country,
+        status: v1.status,
+        investor_funds_raised: v1.investor_funds_raised,
+        is_initialized: v1.is_initialized,
+        investor_count: v1.investor_count,
+        lock_up_period: v1.lock_up_period,
+        unit_cost_of_investment_trusts: v1.unit_cost_of_investment_trusts,
+        decimals: v1.decimals,
+        quote_mint: v1.quote_mint,
+        settlement_vault: v1.settlement_vault,
+        unit_mint: v1.unit_mint,
+        total_units_investment_trusts: v1.total_units_investment_trusts,
+        deposit_account: v1.deposit_account,
+        offering: Offering {
+            status: v1.offering.status,
+            soft_cap: v1.offering.soft_cap,
+            hard_cap: v1.offering.hard_cap,
+            min_ticket: v1.offering.min_ticket,
+            start_time: v1.offering.start_time,
+            end_time: v1.offering.end_time,
+            raised: v1.offering.raised,
+            escrow: v1.offering.escrow,
+            // an offering under way keeps the unit cost it was configured at
+            price: v1.unit_cost_of_investment_trusts,
+        },
+        version: RealEstateInvestmentTrustScheme::VERSION,
+        net_asset_value: 0,
+        valuation_timestamp: 0,
+        appraisal_hash: [0; 32],
+        reward_per_unit: 0,
+        distribution_count: 0,
+        distribution_vault: Pubkey::default(),
+        last_distribution_expiry: 0,
+        income_epoch_count: 0,
+        non_compliant_epochs: 0,
+        redemption_window: 0,
+        redemption_window_start: 0,
+        queued_redemption_units: 0,
+        redemption_escrow: Pubkey::default(),
+        order_count: 0,
+        has_jurisdiction_policy: false,
+        unit_transfer_hook: false,
+        unit_metadata: false,
+        unit_permanent_delegate: false,
+        pooled_units: 0,
+        swept_reward_per_unit: 0,
+        swept_distributions: 0,
+        unswept_distribution_amount: 0,
+        returning_units: false,
+        reserved: [0; 267],
+    };
+
+    Ok(real_estate_investment_trust_scheme.try_to_vec()?)
+}
+
+fn migrate_deposit_base(data: &[u8]) -> Result<Vec<u8>> {
+    if layout_space(data) != DepositBaseV1::INIT_SPACE {
+        return Err(unknown_layout(data, DepositBase::INIT_SPACE));
+    }
+    let v1: DepositBaseV1 = read_layout(data)?;
+
+    let deposit_base = DepositBase {
+        owner: v1.owner,
+        real_estate_investment_trust_scheme: v1.real_estate_investment_trust_scheme,
+        admin_auth_bump: v1.admin_auth_bump,
+        admin_treasury_vault_bump: v1.admin_treasury_vault_bump,
+        is_initialized: v1.is_initialized,
+        version: DepositBase::VERSION,
+        reserved: [0; 64],
+    };
+
+    Ok(deposit_base.try_to_vec()?)
+}
+
+fn migrate_holding(data: &[u8]) -> Result<Vec<u8>> {
+    if layout_space(data) != HoldingV1::INIT_SPACE {
+        return Err(unknown_layout(data, Holding::INIT_SPACE));
+    }
+    let v1: HoldingV1 = read_layout(data)?;
+
+    let holding = Holding {
+        real_estate_investment_trust_scheme: v1.real_estate_investment_trust_scheme,
+        investor: v1.investor,
+        owner: v1.owner,
+        units: v1.units,
+        cost_basis: v1.cost_basis,
+        first_purchase_slot: v1.first_purchase_slot,
+        locked_until: v1.locked_until,
+        is_initialized: v1.is_initialized,
+        version: Holding::VERSION,
+        reward_checkpoint: 0,
+        accrued_distributions: 0,
+        accrued_through: 0,
+        drip: false,
+        queued_units: 0,
+        listed_units: 0,
+        frozen: false,
+        compliance_reason: ComplianceReason::None,
+        accrued_from: 0,
+        reserved: [0; 69],
+    };
+
+    Ok(holding.try_to_vec()?)
+}
+
+// subscriptions carry no version, only the u32 layout is migrated
+fn migrate_subscription(data: &[u8]) -> Result<Vec<u8>> {
+    if layout_space(data) != SubscriptionV0::INIT_SPACE {
+        return Err(unknown_layout(data, Subscription::INIT_SPACE));
+    }
+    let v0: SubscriptionV0 = read_layout(data)?;
+
+    let subscription = Subscription {
+        real_estate_investment_trust_scheme: v0.real_estate_investment_trust_scheme,
+        investor: v0.investor,
+        owner: v0.owner,
+        amount: to_quote_value(v0.amount)?,
+        claimed: v0.claimed,
+        is_initialized: v0.is_initialized,
+    };
+
+    Ok(subscription.try_to_vec()?)
+}
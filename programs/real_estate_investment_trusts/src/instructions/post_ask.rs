@@ -0,0 +1,179 @@
+//! PostAsk instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{quote_to_base_units, units_to_base_units},
+        state::{
+            configs::InvestmentTrustsConfigs, holding::Holding, investor::Investor, order::Order,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+        token::{transfer_checked_with_hook, unit_transfer_authority},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: PostAskParams)]
+pub struct PostAsk<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(has_one = owner,
+        constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub investor: Account<'info, Investor>,
+    #[account(mut,
+        constraint = holding.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Order::INIT_SPACE,
+        seeds = [
+            b"order",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            real_estate_investment_trust_scheme.order_count.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub order: Account<'info, Order>,
+    // offered units are held under the scheme until the ask is filled or cancelled
+    #[account(
+        init,
+        payer = owner,
+        token::mint = unit_mint,
+        token::authority = real_estate_investment_trust_scheme,
+        seeds = [b"order-escrow", order.key().as_ref()],
+        bump
+    )]
+    pub order_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // seller's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: InterfaceAccount<'info, TokenAccount>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associate_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PostAskParams {
+    pub units: u64, // whole units of investment trusts offered
+    pub price: u64, // price per unit, fixed-point quote value
+}
+
+pub fn post_ask<'info>(
+    ctx: Context<'_, '_, '_, 'info, PostAsk<'info>>,
+    params: &PostAskParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.units == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    if params.price == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
+    }
+
+    // units must stay payable exactly in the quote mint's smallest unit
+    quote_to_base_units(params.price, ctx.accounts.mint_token.decimals)?;
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let holding = &mut ctx.accounts.holding;
+    let order = &mut ctx.accounts.order;
+    let now = Clock::get()?.unix_timestamp;
+
+    // only investors with a current attestation may trade
+    ctx.accounts.investor.check_attestation(now)?;
+
+    // units cannot be sold during the lock-up that follows a purchase
+    if now < holding.locked_until {
+        return Err(RealEstateInvestmentTrustsError::HoldingLocked.into());
+    }
+
+    // units already queued or offered in an ask cannot be offered again
+    if params.units > holding.free_units()? {
+        return Err(RealEstateInvestmentTrustsError::InsufficientUnits.into());
+    }
+
+    holding.listed_units = holding
+        .listed_units
+        .checked_add(params.units)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // order
+    order.real_estate_investment_trust_scheme = real_estate_investment_trust_scheme.key();
+    order.index = real_estate_investment_trust_scheme.order_count;
+    order.investor = ctx.accounts.investor.key();
+    order.owner = *ctx.accounts.owner.key;
+    order.units = params.units;
+    order.price = params.price;
+    order.escrow = ctx.accounts.order_escrow.key();
+    order.created_at = now;
+    order.is_initialized = true;
+
+    real_estate_investment_trust_scheme.order_count = real_estate_investment_trust_scheme
+        .order_count
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    let units = units_to_base_units(params.units, real_estate_investment_trust_scheme.decimals)?;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    // offered units leave the seller's wallet until the ask is filled or cancelled
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.investor_units.to_account_info(),
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.order_escrow.to_account_info(),
+                authority: unit_transfer_authority(
+                    real_estate_investment_trust_scheme,
+                    &ctx.accounts.owner.to_account_info(),
+                ),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        units,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+//! PostIncomeEpoch instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::quote_to_base_units,
+        state::{
+            configs::InvestmentTrustsConfigs, income_epoch::IncomeEpoch,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+};
+
+#[derive(Accounts)]
+#[instruction(params: PostIncomeEpochParams)]
+pub struct PostIncomeEpoch<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [b"investment-trusts-configs"],
+        bump
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut, has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + IncomeEpoch::INIT_SPACE,
+        seeds = [
+            b"income-epoch",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            real_estate_investment_trust_scheme.income_epoch_count.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub income_epoch: Account<'info, IncomeEpoch>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PostIncomeEpochParams {
+    pub net_distributable_income: u64, // income for the period, fixed-point quote value
+    pub period_start: i64,             // unix timestamp the period starts
+    pub period_end: i64,               // unix timestamp the period ends
+}
+
+// only income REITs are held to a payout ratio
+const INCOME_REIT: u8 = 2;
+
+pub fn post_income_epoch(
+    ctx: Context<PostIncomeEpoch>,
+    params: &PostIncomeEpochParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.period_start >= params.period_end {
+        return Err(RealEstateInvestmentTrustsError::InvalidIncomePeriod.into());
+    }
+
+    let investment_trusts_configs = &ctx.accounts.investment_trusts_configs;
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let income_epoch = &mut ctx.accounts.income_epoch;
+
+    if real_estate_investment_trust_scheme.issuer.type_of_reit != INCOME_REIT {
+        return Err(RealEstateInvestmentTrustsError::NotIncomeReit.into());
+    }
+
+    // the ratio in force when the income is reported applies to the period
+    income_epoch.real_estate_investment_trust_scheme = real_estate_investment_trust_scheme.key();
+    income_epoch.index = real_estate_investment_trust_scheme.income_epoch_count;
+    income_epoch.period_start = params.period_start;
+    income_epoch.period_end = params.period_end;
+    income_epoch.net_distributable_income = quote_to_base_units(
+        params.net_distributable_income,
+        ctx.accounts.mint_token.decimals,
+    )?;
+    income_epoch.min_payout_ratio_bps = investment_trusts_configs.min_payout_ratio_bps;
+    income_epoch.deadline = params
+        .period_end
+        .checked_add(investment_trusts_configs.payout_grace_period)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    income_epoch.is_initialized = true;
+
+    real_estate_investment_trust_scheme.income_epoch_count = real_estate_investment_trust_scheme
+        .income_epoch_count
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! RefundSubscription instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::quote_to_base_units,
+        state::{
+            offering::OfferingStatus,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            subscription::Subscription,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+pub struct RefundSubscription<'info> {
+    #[account(
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.offering.status == OfferingStatus::Failed @ RealEstateInvestmentTrustsError::OfferingNotFailed,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(mut, has_one = owner,
+        constraint = !subscription.claimed @ RealEstateInvestmentTrustsError::SubscriptionAlreadyClaimed,
+        seeds = [b"subscription", real_estate_investment_trust_scheme.key().as_ref(), subscription.investor.as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    #[account(mut, address = real_estate_investment_trust_scheme.offering.escrow)]
+    pub offering_escrow: InterfaceAccount<'info, TokenAccount>,
+    // investor's quote token account, receives the refund
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub recipient_tokens: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    pub owner: Signer<'info>,
+    // token program of the quote mint
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn refund_subscription(ctx: Context<RefundSubscription>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &ctx.accounts.real_estate_investment_trust_scheme;
+    let subscription = &mut ctx.accounts.subscription;
+
+    subscription.claimed = true;
+
+    // soft cap missed, return the subscription from escrow
+    let refund = quote_to_base_units(subscription.amount, ctx.accounts.mint_token.decimals)?;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.offering_escrow.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.recipient_tokens.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        refund,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    Ok(())
+}
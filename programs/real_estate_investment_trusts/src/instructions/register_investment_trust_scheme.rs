@@ -3,13 +3,19 @@
 use {
     crate::{
         error::RealEstateInvestmentTrustsError,
+        math::{pow10, quote_to_base_units},
         state::{
             configs::InvestmentTrustsConfigs, deposit_base::DepositBase,
-            market_issuer::MarketIssuer,
+            issuer_registry_entry::IssuerRegistryEntry, market_issuer::MarketIssuer,
             real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_promoter::SchemePromoter, scheme_status::SchemeStatus,
         },
     },
     anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{Mint, TokenAccount, TokenInterface},
+    },
 };
 
 #[derive(Accounts)]
@@ -19,6 +25,15 @@ pub struct RegisterRealEstateInvestmentTrustScheme<'info> {
         mut, constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized
     )]
     pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // per promoter counter, created with the promoter's first scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + SchemePromoter::INIT_SPACE,
+        seeds = [b"scheme-promoter", owner.key().as_ref()],
+        bump
+    )]
+    pub scheme_promoter: Account<'info, SchemePromoter>,
     // init means to create account
     // bump to use unique address for account
     #[account(
@@ -26,12 +41,24 @@ pub struct RegisterRealEstateInvestmentTrustScheme<'info> {
         payer = owner,
         space = 8 + RealEstateInvestmentTrustScheme::INIT_SPACE,
         constraint = !real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountAlreadyInitialized,
-        seeds = [b"investment-trust-scheme", owner.key().as_ref()],
+        constraint = params.scheme_id == scheme_promoter.scheme_count @ RealEstateInvestmentTrustsError::InvalidSchemeId,
+        seeds = [b"investment-trust-scheme", owner.key().as_ref(), params.scheme_id.to_le_bytes().as_ref()],
         bump
     )]
     pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + IssuerRegistryEntry::INIT_SPACE,
+        seeds = [b"issuer-registry", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub issuer_registry_entry: Account<'info, IssuerRegistryEntry>,
+    // one deposit base per scheme, derived from the scheme address
     #[account(init, payer = owner, space = 8 + DepositBase::INIT_SPACE,
-        constraint = !deposit_account.is_initialized @ RealEstateInvestmentTrustsError::AccountAlreadyInitialized
+        constraint = !deposit_account.is_initialized @ RealEstateInvestmentTrustsError::AccountAlreadyInitialized,
+        seeds = [b"deposit-base", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
     )]
     pub deposit_account: Account<'info, DepositBase>,
     #[account(seeds = [b"auth", deposit_account.key().as_ref()], bump)]
@@ -39,26 +66,33 @@ pub struct RegisterRealEstateInvestmentTrustScheme<'info> {
     pub pda_auth: UncheckedAccount<'info>,
     #[account(mut, seeds = [b"treasury-vault", pda_auth.key().as_ref()], bump)]
     pub treasury_vault: SystemAccount<'info>,
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    // treasury token account that receives purchases and pays out sales
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = quote_mint,
+        associated_token::authority = treasury_vault
+    )]
+    pub settlement_vault: InterfaceAccount<'info, TokenAccount>,
     // mut makes it changeble (mutable)
     #[account(mut)]
     pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RegisterRealEstateInvestmentTrustSchemeParams {
+    scheme_id: u64,                      // next scheme id of the promoter
     issuer: MarketIssuer,                // market issuer details
     country: String,                     // home country where trust scheme is implemented
-    unit_cost_of_investment_trusts: u32, // unit cost of investment trusts
+    unit_cost_of_investment_trusts: u64, // unit cost of investment trusts, fixed-point quote value
     decimals: u8,                        // decimals for the token mint
+    lock_up_period: i64,                 // seconds after a purchase before units can be sold
 }
 
-// issuer length
-const ISSUER_LENGTH: usize = 30;
-// name length
-const NAME_LENGTH: usize = 30;
-// listing date length
-const LISTING_DATE_LENGTH: usize = 20;
 // country length
 const COUNTRY_LENGTH: usize = 3;
 const COUNTRY_LENGTH_2: usize = 2;
@@ -69,74 +103,78 @@ pub fn register_investment_trust_scheme(
 ) -> Result<()> {
     // validate inputs
     msg!("Validate inputs");
-    if params.issuer.issuer.as_bytes().len() > 0
-        && params.issuer.issuer.as_bytes().len() <= ISSUER_LENGTH
-    {
-    } else {
-        return Err(RealEstateInvestmentTrustsError::InvalidIssuerLength.into());
-    }
+    params.issuer.validate()?;
 
-    if params.issuer.name.as_bytes().len() > 0 && params.issuer.name.as_bytes().len() <= NAME_LENGTH
-    {
-    } else {
-        return Err(RealEstateInvestmentTrustsError::InvalidNameLength.into());
+    if params.country.len() != COUNTRY_LENGTH && params.country.len() != COUNTRY_LENGTH_2 {
+        return Err(RealEstateInvestmentTrustsError::InvalidCountryLength.into());
     }
 
-    // 1 - DevelopmentRealEstateInvestmentTrusts i.e (D-REITs)
-    // 2 - IncomeRealEstateInvestmentTrust i.e (I-REITs)
-
-    let is_valid_reit = {
-        match params.issuer.type_of_reit {
-            1 | 2 => true,
-            _ => false,
-        }
-    };
-
-    if !is_valid_reit {
-        return Err(RealEstateInvestmentTrustsError::InvalidTypeOfReit.into());
+    if params.decimals == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
     }
 
-    if params.issuer.listing_date.as_bytes().len() > 0
-        && params.issuer.listing_date.as_bytes().len() <= LISTING_DATE_LENGTH
-    {
-    } else {
-        return Err(RealEstateInvestmentTrustsError::InvalidListingDateLength.into());
-    }
+    // whole units must be expressible in the unit mint's smallest unit
+    pow10(params.decimals)?;
 
-    if params.country.as_bytes().len() != COUNTRY_LENGTH
-        && params.country.as_bytes().len() != COUNTRY_LENGTH_2
-    {
-        return Err(RealEstateInvestmentTrustsError::InvalidCountryLength.into());
+    // the unit cost must be payable exactly in the quote mint's smallest unit
+    if params.unit_cost_of_investment_trusts == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
     }
+    quote_to_base_units(
+        params.unit_cost_of_investment_trusts,
+        ctx.accounts.quote_mint.decimals,
+    )?;
 
-    if params.decimals == 0 {
+    if params.lock_up_period < 0 {
         return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
     }
 
     let deposit_account = &mut ctx.accounts.deposit_account;
     let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
     let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+    let scheme_promoter = &mut ctx.accounts.scheme_promoter;
+    let issuer_registry_entry = &mut ctx.accounts.issuer_registry_entry;
 
     // deposit account
     // * - means dereferencing
     deposit_account.owner = *ctx.accounts.owner.key;
+    deposit_account.real_estate_investment_trust_scheme = real_estate_investment_trust_scheme.key();
     deposit_account.admin_auth_bump = ctx.bumps.pda_auth;
     deposit_account.admin_treasury_vault_bump = Some(ctx.bumps.treasury_vault);
     deposit_account.is_initialized = true;
+    deposit_account.version = DepositBase::VERSION;
+
+    // scheme promoter
+    scheme_promoter.owner = *ctx.accounts.owner.key;
+    scheme_promoter.scheme_count = scheme_promoter
+        .scheme_count
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    scheme_promoter.is_initialized = true;
 
     // real_estate_investment_trust_scheme
     real_estate_investment_trust_scheme.owner = *ctx.accounts.owner.key;
+    real_estate_investment_trust_scheme.scheme_id = params.scheme_id;
+    real_estate_investment_trust_scheme.bump = ctx.bumps.real_estate_investment_trust_scheme;
     real_estate_investment_trust_scheme.issuer.issuer = params.issuer.issuer.to_string();
     real_estate_investment_trust_scheme.issuer.name = params.issuer.name.to_string();
     real_estate_investment_trust_scheme.issuer.type_of_reit = params.issuer.type_of_reit;
     real_estate_investment_trust_scheme.issuer.listing_date =
         params.issuer.listing_date.to_string();
     real_estate_investment_trust_scheme.country = params.country.to_string();
-    real_estate_investment_trust_scheme.active = true;
+    // a scheme only goes live once a scheme approver has listed it
+    real_estate_investment_trust_scheme.status = SchemeStatus::Pending;
     real_estate_investment_trust_scheme.is_initialized = true;
+    real_estate_investment_trust_scheme.version = RealEstateInvestmentTrustScheme::VERSION;
     real_estate_investment_trust_scheme.unit_cost_of_investment_trusts =
         params.unit_cost_of_investment_trusts;
     real_estate_investment_trust_scheme.decimals = params.decimals;
+    real_estate_investment_trust_scheme.lock_up_period = params.lock_up_period;
+    // the registered unit cost stands as the first valuation until a valuer posts one
+    real_estate_investment_trust_scheme.valuation_timestamp = Clock::get()?.unix_timestamp;
+    real_estate_investment_trust_scheme.quote_mint = ctx.accounts.quote_mint.key();
+    real_estate_investment_trust_scheme.settlement_vault = ctx.accounts.settlement_vault.key();
+    real_estate_investment_trust_scheme.deposit_account = deposit_account.key();
 
     let market_issuer = MarketIssuer {
         issuer: params.issuer.issuer.to_string(),
@@ -145,8 +183,18 @@ pub fn register_investment_trust_scheme(
         listing_date: params.issuer.listing_date.to_string(),
     };
 
+    // issuer_registry_entry
+    issuer_registry_entry.real_estate_investment_trust_scheme =
+        real_estate_investment_trust_scheme.key();
+    issuer_registry_entry.issuer = market_issuer;
+    issuer_registry_entry.registered_at = Clock::get()?.unix_timestamp;
+    issuer_registry_entry.is_initialized = true;
+
     // investment_trusts_configs
-    investment_trusts_configs.issuers.push(market_issuer);
+    investment_trusts_configs.issuer_count = investment_trusts_configs
+        .issuer_count
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
 
     Ok(())
 }
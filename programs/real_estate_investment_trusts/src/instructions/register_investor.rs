@@ -42,16 +42,12 @@ pub fn register_investor(
 ) -> Result<()> {
     // validate inputs
     msg!("Validate inputs");
-    if params.full_names.as_bytes().len() > 0
-        && params.full_names.as_bytes().len() <= FULL_NAMES_LENGTH
-    {
+    if !params.full_names.is_empty() && params.full_names.len() <= FULL_NAMES_LENGTH {
     } else {
         return Err(RealEstateInvestmentTrustsError::InvalidFullNamesLength.into());
     }
 
-    if params.country.as_bytes().len() != COUNTRY_LENGTH
-        && params.country.as_bytes().len() != COUNTRY_LENGTH_2
-    {
+    if params.country.len() != COUNTRY_LENGTH && params.country.len() != COUNTRY_LENGTH_2 {
         return Err(RealEstateInvestmentTrustsError::InvalidCountryLength.into());
     }
 
@@ -61,7 +57,9 @@ pub fn register_investor(
     investor.owner = *ctx.accounts.owner.key;
     investor.full_names = params.full_names.to_string();
     investor.country = params.country.to_string();
-    investor.active = true;
+    // the investor is activated once a compliance officer attests them
+    investor.active = false;
+    investor.version = Investor::VERSION;
 
     Ok(())
 }
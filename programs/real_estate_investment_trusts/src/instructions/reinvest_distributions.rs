@@ -0,0 +1,203 @@
+//! ReinvestDistributions instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{base_units_to_quote, quote_to_base_units, quote_value, units_to_base_units},
+        state::{
+            configs::InvestmentTrustsConfigs, distribution::Distribution, holding::Holding,
+            investor::Investor, jurisdiction_policy::JurisdictionPolicy, offering::OfferingStatus,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ReinvestDistributions<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        constraint = matches!(real_estate_investment_trust_scheme.offering.status, OfferingStatus::None | OfferingStatus::Succeeded) @ RealEstateInvestmentTrustsError::OfferingInProgress,
+        constraint = real_estate_investment_trust_scheme.non_compliant_epochs == 0 @ RealEstateInvestmentTrustsError::SchemeNonCompliant,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // required once the scheme has a jurisdiction policy
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Option<Account<'info, JurisdictionPolicy>>,
+    #[account(mut, address = holding.investor,
+        constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub investor: Account<'info, Investor>,
+    #[account(mut,
+        constraint = !holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        constraint = holding.drip @ RealEstateInvestmentTrustsError::DripNotElected,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    // oldest distribution in the holding's accrued funds, they expire with it
+    #[account(
+        seeds = [
+            b"distribution",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            distribution.index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+    #[account(mut, address = real_estate_investment_trust_scheme.distribution_vault)]
+    pub distribution_vault: InterfaceAccount<'info, TokenAccount>,
+    // scheme's settlement vault, receives the reinvested funds
+    #[account(mut, address = real_estate_investment_trust_scheme.settlement_vault)]
+    pub settlement_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // holder's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = holding.owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: InterfaceAccount<'info, TokenAccount>,
+    // any signer may reinvest for a holding that elected it
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reinvest_distributions(ctx: Context<ReinvestDistributions>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let investor = &mut ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+    let decimals = ctx.accounts.mint_token.decimals;
+    let unit_cost_of_investment_trusts =
+        real_estate_investment_trust_scheme.unit_cost_of_investment_trusts;
+
+    // units are issued at the latest valuation, which must not be stale
+    let now = Clock::get()?.unix_timestamp;
+    real_estate_investment_trust_scheme
+        .check_valuation_fresh(ctx.accounts.investment_trusts_configs.max_nav_age, now)?;
+
+    // reinvesting is a purchase, only eligible investors may make it
+    investor.check_eligibility(
+        &ctx.accounts.investment_trusts_configs,
+        real_estate_investment_trust_scheme,
+        ctx.accounts.jurisdiction_policy.as_deref(),
+        now,
+    )?;
+
+    holding.settle_distributions(real_estate_investment_trust_scheme)?;
+
+    // whole units the accrued distributions pay for, the remainder stays accrued
+    let accrued = base_units_to_quote(holding.accrued_distributions, decimals)?;
+    let unit_investment_trusts = accrued
+        .checked_div(unit_cost_of_investment_trusts)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    if unit_investment_trusts == 0 {
+        return Err(RealEstateInvestmentTrustsError::NothingToReinvest.into());
+    }
+
+    // funds can no longer be reinvested once the oldest distribution they include expires
+    let distribution = &ctx.accounts.distribution;
+    if distribution.index != holding.accrued_from {
+        return Err(RealEstateInvestmentTrustsError::DistributionMismatch.into());
+    }
+    if now >= distribution.expires_at {
+        return Err(RealEstateInvestmentTrustsError::DistributionExpired.into());
+    }
+
+    let cost = quote_value(unit_investment_trusts, unit_cost_of_investment_trusts)?;
+    let amount = quote_to_base_units(cost, decimals)?;
+
+    holding.accrued_distributions = holding
+        .accrued_distributions
+        .checked_sub(amount)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // Increment investor_funds_raised with the reinvested funds
+    real_estate_investment_trust_scheme.investor_funds_raised = real_estate_investment_trust_scheme
+        .investor_funds_raised
+        .checked_add(cost)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // Increment the scheme's issued units with new unit_investment_trusts
+    real_estate_investment_trust_scheme.total_units_investment_trusts =
+        real_estate_investment_trust_scheme
+            .total_units_investment_trusts
+            .checked_add(unit_investment_trusts)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // reinvested units do not restart the lock-up on the position
+    holding.credit(
+        real_estate_investment_trust_scheme,
+        investor,
+        unit_investment_trusts,
+        cost,
+    )?;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    // the reinvested funds pay for the units like a purchase would
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.distribution_vault.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.settlement_vault.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        decimals,
+    )?;
+
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.investor_units.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        units,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+//! RemoveLiquidity instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{quote_value, units_to_base_units},
+        state::{
+            holding::Holding,
+            investor::Investor,
+            pool::{LiquidityProvider, Pool},
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+        token::return_units_with_hook,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: RemoveLiquidityParams)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Box<Account<'info, RealEstateInvestmentTrustScheme>>,
+    #[account(mut, has_one = unit_vault, has_one = quote_vault,
+        seeds = [b"pool", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    // shares can be withdrawn after the provider is removed from the whitelist
+    #[account(mut, has_one = owner,
+        seeds = [b"liquidity-provider", pool.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub liquidity_provider: Box<Account<'info, LiquidityProvider>>,
+    #[account(mut, has_one = owner)]
+    pub investor: Box<Account<'info, Investor>>,
+    // init_if_needed recreates a position closed after the units were pooled
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Holding::INIT_SPACE,
+        constraint = !holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Box<Account<'info, Holding>>,
+    #[account(mut)]
+    pub unit_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    // liquidity provider's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: Box<InterfaceAccount<'info, TokenAccount>>,
+    // liquidity provider's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub recipient_tokens: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: Box<InterfaceAccount<'info, Mint>>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associate_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveLiquidityParams {
+    pub shares: u64, // pool shares redeemed for their part of both reserves
+}
+
+pub fn remove_liquidity<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+    params: &RemoveLiquidityParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.shares == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let pool = &mut ctx.accounts.pool;
+    let liquidity_provider = &mut ctx.accounts.liquidity_provider;
+    let investor = &mut ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+    let decimals = ctx.accounts.mint_token.decimals;
+
+    if params.shares > liquidity_provider.shares {
+        return Err(RealEstateInvestmentTrustsError::InsufficientShares.into());
+    }
+
+    // the shares' part of each reserve, rounding in favour of the pool
+    let unit_investment_trusts = (pool.unit_reserve as u128)
+        .checked_mul(params.shares as u128)
+        .and_then(|value| value.checked_div(pool.total_shares as u128))
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?
+        as u64;
+    let amount = (pool.quote_reserve as u128)
+        .checked_mul(params.shares as u128)
+        .and_then(|value| value.checked_div(pool.total_shares as u128))
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?
+        as u64;
+
+    liquidity_provider.shares -= params.shares;
+    pool.total_shares -= params.shares;
+    pool.unit_reserve -= unit_investment_trusts;
+    pool.quote_reserve -= amount;
+    real_estate_investment_trust_scheme.pooled_units = pool.unit_reserve;
+
+    // holding
+    let clock = Clock::get()?;
+    holding.initialize(
+        real_estate_investment_trust_scheme.key(),
+        investor.key(),
+        *ctx.accounts.owner.key,
+        clock.slot,
+    );
+
+    // withdrawn units carry the cost of the latest valuation
+    let cost = quote_value(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.unit_cost_of_investment_trusts,
+    )?;
+    holding.credit(
+        real_estate_investment_trust_scheme,
+        investor,
+        unit_investment_trusts,
+        cost,
+    )?;
+
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    if units > 0 {
+        let unit_decimals = real_estate_investment_trust_scheme.decimals;
+
+        return_units_with_hook(
+            real_estate_investment_trust_scheme,
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.unit_vault.to_account_info(),
+                    mint: ctx.accounts.unit_mint.to_account_info(),
+                    to: ctx.accounts.investor_units.to_account_info(),
+                    authority: real_estate_investment_trust_scheme.to_account_info(),
+                },
+                signer,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            units,
+            unit_decimals,
+        )?;
+    }
+
+    if amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.quote_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.quote_vault.to_account_info(),
+                    mint: ctx.accounts.mint_token.to_account_info(),
+                    to: ctx.accounts.recipient_tokens.to_account_info(),
+                    authority: real_estate_investment_trust_scheme.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+            decimals,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! RevokeRole instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{configs::InvestmentTrustsConfigs, role::Role},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: RevokeRoleParams)]
+pub struct RevokeRole<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Admin) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevokeRoleParams {
+    pub role: Role,        // role to revoke
+    pub authority: Pubkey, // publickey losing the role
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>, params: &RevokeRoleParams) -> Result<()> {
+    msg!("Validate inputs");
+    // the admin role can only be handed over, otherwise the registry would be orphaned
+    if params.role == Role::Admin {
+        return Err(RealEstateInvestmentTrustsError::InvalidRole.into());
+    }
+
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    let position = investment_trusts_configs
+        .roles
+        .iter()
+        .position(|assignment| {
+            assignment.role == params.role && assignment.authority == params.authority
+        })
+        .ok_or(RealEstateInvestmentTrustsError::RoleNotFound)?;
+
+    investment_trusts_configs.roles.remove(position);
+
+    Ok(())
+}
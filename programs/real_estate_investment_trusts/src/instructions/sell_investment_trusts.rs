@@ -3,59 +3,96 @@
 use {
     crate::{
         error::RealEstateInvestmentTrustsError,
+        math::units_to_base_units,
         state::{
-            deposit_base::DepositBase, investor::Investor,
+            configs::InvestmentTrustsConfigs, holding::Holding, investor::Investor,
             real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            redemption::RedemptionRequest, scheme_status::SchemeStatus,
         },
+        token::{transfer_checked_with_hook, unit_transfer_authority},
     },
     anchor_lang::prelude::*,
     anchor_spl::{
         associated_token::AssociatedToken,
-        token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+        token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
     },
 };
 
 #[derive(Accounts)]
 #[instruction(params: SellInvestmentTrustsParams)]
 pub struct SellInvestmentTrusts<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
     #[account(mut,
-        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
     )]
     pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
     #[account(mut,has_one = owner,
         constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
     )]
     pub investor: Account<'info, Investor>,
-    #[account(mut)]
-    pub sender_tokens: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub recipient_tokens: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub mint_token: Account<'info, Mint>,
     #[account(mut,
-        constraint = deposit_account.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized
+        constraint = holding.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    // init_if_needed creates the request on the investor's first redemption in this scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + RedemptionRequest::INIT_SPACE,
+        seeds = [b"redemption", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
     )]
-    pub deposit_account: Account<'info, DepositBase>,
-    #[account(seeds = [b"auth", deposit_account.key().as_ref()], bump)]
-    /// CHECK: no need to check this.
-    pub pda_auth: UncheckedAccount<'info>,
-    #[account(mut, seeds = [b"treasury-vault", pda_auth.key().as_ref()], bump)]
-    pub treasury_vault: SystemAccount<'info>,
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    // queued units are held under the scheme until their window settles
+    #[account(
+        init_if_needed,
+        payer = owner,
+        token::mint = unit_mint,
+        token::authority = real_estate_investment_trust_scheme,
+        seeds = [b"redemption-escrow", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub redemption_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // investor's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: InterfaceAccount<'info, TokenAccount>,
     // mut makes it changeble (mutable)
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associate_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SellInvestmentTrustsParams {
-    pub amount: u32,
+    pub amount: u64, // whole units of investment trusts to redeem
 }
 
-pub fn sell_investment_trusts(
-    ctx: Context<SellInvestmentTrusts>,
+// units are not paid out here, they join the open redemption window
+// and are filled by settle_redemption once close_redemption_window gates it
+pub fn sell_investment_trusts<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellInvestmentTrusts<'info>>,
     params: &SellInvestmentTrustsParams,
 ) -> Result<()> {
     msg!("Validate inputs");
@@ -64,76 +101,87 @@ pub fn sell_investment_trusts(
     }
 
     let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
-    let investor = &mut ctx.accounts.investor;
-    let sender_tokens = &ctx.accounts.sender_tokens;
-    let recipient_tokens = &ctx.accounts.recipient_tokens;
-    let mint_token = &ctx.accounts.mint_token;
-    let deposit_account = &ctx.accounts.deposit_account;
-    let pda_auth = &mut ctx.accounts.pda_auth;
-    let treasury_vault = &mut ctx.accounts.treasury_vault;
-    let token_program = &ctx.accounts.token_program;
-    let unit_cost_of_investment_trusts: u32 =
-        real_estate_investment_trust_scheme.unit_cost_of_investment_trusts;
-    let investor_funds_raised = real_estate_investment_trust_scheme.investor_funds_raised;
-    let total_units_investment_trusts: u32 = investor.total_units_investment_trusts;
-    let available_funds: u32 = investor.available_funds;
-    let decimals: u8 = real_estate_investment_trust_scheme.decimals;
+    let investor = &ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let redemption_request = &mut ctx.accounts.redemption_request;
     let _amount = params.amount;
 
-    // investor's available funds should exceed transfer amount
-    if available_funds > _amount {
-    } else {
-        return Err(RealEstateInvestmentTrustsError::InsufficientFunds.into());
-    }
+    // only investors with a current attestation may trade
+    let now = Clock::get()?.unix_timestamp;
+    investor.check_attestation(now)?;
 
-    // Get unit_investment_trusts from the product of unit_cost_of_investment_trusts and actual_amount
-    let unit_investment_trusts = unit_cost_of_investment_trusts
-        .checked_mul(_amount)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    // units cannot be sold during the lock-up that follows a purchase
+    if now < holding.locked_until {
+        return Err(RealEstateInvestmentTrustsError::HoldingLocked.into());
+    }
 
-    // Deduct sold unit_investment_trusts from investor's total_units_investment_trusts
-    investor.total_units_investment_trusts = total_units_investment_trusts
-        .checked_sub(unit_investment_trusts)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    // units already queued or offered in an ask cannot be queued again
+    if _amount > holding.free_units()? {
+        return Err(RealEstateInvestmentTrustsError::InsufficientUnits.into());
+    }
 
-    // Deduct actual_amount(sold unit_investment_trusts) from investor's available funds
-    investor.available_funds = available_funds
-        .checked_sub(_amount)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    // units carried over from a closed window must settle before more are queued
+    if redemption_request.units > 0
+        && redemption_request.window != real_estate_investment_trust_scheme.redemption_window
+    {
+        return Err(RealEstateInvestmentTrustsError::RedemptionPending.into());
+    }
 
-    // Deduct actual_amount(sold unit_investment_trusts) from investor_funds_raised
-    real_estate_investment_trust_scheme.investor_funds_raised = investor_funds_raised
-        .checked_sub(_amount)
+    // redemption request
+    if !redemption_request.is_initialized {
+        redemption_request.real_estate_investment_trust_scheme =
+            real_estate_investment_trust_scheme.key();
+        redemption_request.investor = investor.key();
+        redemption_request.owner = *ctx.accounts.owner.key;
+        redemption_request.is_initialized = true;
+    }
+    redemption_request.window = real_estate_investment_trust_scheme.redemption_window;
+    redemption_request.units = redemption_request
+        .units
+        .checked_add(_amount)
         .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
 
-    let base: u32 = 10;
-    let exponent = real_estate_investment_trust_scheme.decimals as u32;
-    // lets get the amount in decimal format
-    // 10 ** 9 * 3(base 10, 9 decimals, 3 amount), // 3 amount of token to transfer (in smallest unit i.e 9 decimals)
-    let result = (base).pow(exponent);
-    let _amount = (_amount as u64)
-        .checked_mul(result as u64)
+    holding.queued_units = holding
+        .queued_units
+        .checked_add(_amount)
         .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
 
-    // Transfer funds from treasury vault to recipient
-    let cpi_accounts = TransferChecked {
-        from: sender_tokens.to_account_info(),
-        mint: mint_token.to_account_info(),
-        to: recipient_tokens.to_account_info(),
-        authority: treasury_vault.to_account_info(),
-    };
+    // the first request opens the first window
+    if real_estate_investment_trust_scheme.redemption_window_start == 0 {
+        real_estate_investment_trust_scheme.redemption_window_start = now;
+    }
+    real_estate_investment_trust_scheme.queued_redemption_units =
+        real_estate_investment_trust_scheme
+            .queued_redemption_units
+            .checked_add(_amount)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    real_estate_investment_trust_scheme.redemption_escrow = ctx.accounts.redemption_escrow.key();
 
-    let seeds = &[
-        b"treasury-vault",
-        pda_auth.to_account_info().key.as_ref(),
-        &[deposit_account.admin_treasury_vault_bump.unwrap()],
-    ];
+    let units = units_to_base_units(_amount, real_estate_investment_trust_scheme.decimals)?;
 
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
     let signer = &[&seeds[..]];
 
-    let cpi = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
-
-    transfer_checked(cpi, _amount, decimals)?;
+    // queued units leave the investor's wallet until they are redeemed
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.investor_units.to_account_info(),
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                to: ctx.accounts.redemption_escrow.to_account_info(),
+                authority: unit_transfer_authority(
+                    real_estate_investment_trust_scheme,
+                    &ctx.accounts.owner.to_account_info(),
+                ),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        units,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
 
     Ok(())
 }
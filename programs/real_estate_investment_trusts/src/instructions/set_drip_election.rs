@@ -0,0 +1,54 @@
+//! SetDripElection instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            holding::Holding, investor::Investor,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetDripElectionParams)]
+pub struct SetDripElection<'info> {
+    #[account(
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(has_one = owner)]
+    pub investor: Account<'info, Investor>,
+    #[account(mut, has_one = owner,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetDripElectionParams {
+    pub drip: bool, // reinvest distributions in units instead of paying them out
+}
+
+pub fn set_drip_election(
+    ctx: Context<SetDripElection>,
+    params: &SetDripElectionParams,
+) -> Result<()> {
+    let holding = &mut ctx.accounts.holding;
+
+    holding.drip = params.drip;
+
+    Ok(())
+}
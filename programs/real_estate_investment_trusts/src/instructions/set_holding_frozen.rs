@@ -0,0 +1,144 @@
+//! SetHoldingFrozen instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        events::{HoldingFrozen, HoldingThawed},
+        state::{
+            compliance_reason::ComplianceReason, configs::InvestmentTrustsConfigs,
+            holding::Holding, real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            role::Role,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        freeze_account, thaw_account, FreezeAccount, Mint, ThawAccount, TokenAccount,
+        TokenInterface,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetHoldingFrozenParams)]
+pub struct SetHoldingFrozen<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::ComplianceOfficer) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(mut,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), holding.investor.as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    // holder's unit token account
+    #[account(mut,
+        token::mint = unit_mint,
+        token::authority = holding.owner,
+        token::token_program = token_program
+    )]
+    pub holding_units: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // compliance officer
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetHoldingFrozenParams {
+    pub frozen: bool,             // freeze the holding, or thaw it when false
+    pub reason: ComplianceReason, // recorded on the holding and in the event
+}
+
+pub fn set_holding_frozen(
+    ctx: Context<SetHoldingFrozen>,
+    params: &SetHoldingFrozenParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.reason == ComplianceReason::None {
+        return Err(RealEstateInvestmentTrustsError::InvalidComplianceReason.into());
+    }
+
+    let real_estate_investment_trust_scheme = &ctx.accounts.real_estate_investment_trust_scheme;
+    let holding = &mut ctx.accounts.holding;
+
+    match (holding.frozen, params.frozen) {
+        (true, true) => return Err(RealEstateInvestmentTrustsError::HoldingAlreadyFrozen.into()),
+        (false, false) => return Err(RealEstateInvestmentTrustsError::HoldingNotFrozen.into()),
+        _ => {}
+    }
+
+    // the scheme address is the freeze authority of the unit mint
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let account = ctx.accounts.holding_units.to_account_info();
+    let mint = ctx.accounts.unit_mint.to_account_info();
+    let authority = real_estate_investment_trust_scheme.to_account_info();
+
+    if params.frozen {
+        freeze_account(CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount {
+                account,
+                mint,
+                authority,
+            },
+            signer,
+        ))?;
+    } else {
+        thaw_account(CpiContext::new_with_signer(
+            token_program,
+            ThawAccount {
+                account,
+                mint,
+                authority,
+            },
+            signer,
+        ))?;
+    }
+
+    holding.frozen = params.frozen;
+    holding.compliance_reason = params.reason;
+
+    let real_estate_investment_trust_scheme = real_estate_investment_trust_scheme.key();
+    let holding = holding.key();
+    let token_account = ctx.accounts.holding_units.key();
+    let officer = ctx.accounts.owner.key();
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    if params.frozen {
+        emit!(HoldingFrozen {
+            real_estate_investment_trust_scheme,
+            holding,
+            token_account,
+            reason: params.reason,
+            officer,
+            timestamp,
+        });
+    } else {
+        emit!(HoldingThawed {
+            real_estate_investment_trust_scheme,
+            holding,
+            token_account,
+            reason: params.reason,
+            officer,
+            timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! SetInvestorStatus instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{configs::InvestmentTrustsConfigs, investor::Investor, role::Role},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetInvestorStatusParams)]
+pub struct SetInvestorStatus<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::ComplianceOfficer) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut)]
+    pub investor: Account<'info, Investor>,
+    // compliance officer
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetInvestorStatusParams {
+    pub active: bool, // false blocks the investor from trading regardless of attestation
+}
+
+pub fn set_investor_status(
+    ctx: Context<SetInvestorStatus>,
+    params: &SetInvestorStatusParams,
+) -> Result<()> {
+    let investor = &mut ctx.accounts.investor;
+
+    // reactivating still requires a current attestation to trade
+    if params.active && investor.kyc_level == 0 {
+        return Err(RealEstateInvestmentTrustsError::AttestationMissing.into());
+    }
+
+    investor.active = params.active;
+
+    Ok(())
+}
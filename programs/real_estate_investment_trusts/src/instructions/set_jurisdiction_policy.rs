@@ -0,0 +1,78 @@
+//! SetJurisdictionPolicy instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            jurisdiction_policy::{
+                country_code, JurisdictionMode, JurisdictionPolicy, MAX_JURISDICTIONS,
+            },
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetJurisdictionPolicyParams)]
+pub struct SetJurisdictionPolicy<'info> {
+    #[account(mut, has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // init_if_needed creates the policy on its first configuration
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + JurisdictionPolicy::INIT_SPACE,
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Account<'info, JurisdictionPolicy>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetJurisdictionPolicyParams {
+    pub mode: JurisdictionMode,
+    pub countries: Vec<String>, // country codes replacing the current list
+}
+
+pub fn set_jurisdiction_policy(
+    ctx: Context<SetJurisdictionPolicy>,
+    params: &SetJurisdictionPolicyParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.countries.len() > MAX_JURISDICTIONS {
+        return Err(RealEstateInvestmentTrustsError::TooManyCountries.into());
+    }
+
+    let countries = params
+        .countries
+        .iter()
+        .map(|country| country_code(country))
+        .collect::<Result<Vec<_>>>()?;
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let jurisdiction_policy = &mut ctx.accounts.jurisdiction_policy;
+
+    jurisdiction_policy.real_estate_investment_trust_scheme =
+        real_estate_investment_trust_scheme.key();
+    jurisdiction_policy.mode = params.mode;
+    jurisdiction_policy.countries = countries;
+    jurisdiction_policy.is_initialized = true;
+
+    // from now on every purchase and transfer in the scheme must present the policy
+    real_estate_investment_trust_scheme.has_jurisdiction_policy = true;
+
+    Ok(())
+}
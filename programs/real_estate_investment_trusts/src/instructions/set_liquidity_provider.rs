@@ -0,0 +1,72 @@
+//! SetLiquidityProvider instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            investor::Investor,
+            pool::{LiquidityProvider, Pool},
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetLiquidityProviderParams)]
+pub struct SetLiquidityProvider<'info> {
+    #[account(has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(
+        constraint = pool.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [b"pool", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    // investor being whitelisted
+    #[account(constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus)]
+    pub investor: Account<'info, Investor>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + LiquidityProvider::INIT_SPACE,
+        seeds = [b"liquidity-provider", pool.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub liquidity_provider: Account<'info, LiquidityProvider>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetLiquidityProviderParams {
+    pub whitelisted: bool, // may add liquidity, shares can always be withdrawn
+}
+
+pub fn set_liquidity_provider(
+    ctx: Context<SetLiquidityProvider>,
+    params: &SetLiquidityProviderParams,
+) -> Result<()> {
+    let liquidity_provider = &mut ctx.accounts.liquidity_provider;
+
+    if !liquidity_provider.is_initialized {
+        liquidity_provider.pool = ctx.accounts.pool.key();
+        liquidity_provider.investor = ctx.accounts.investor.key();
+        liquidity_provider.owner = ctx.accounts.investor.owner;
+        liquidity_provider.is_initialized = true;
+    }
+
+    liquidity_provider.whitelisted = params.whitelisted;
+
+    Ok(())
+}
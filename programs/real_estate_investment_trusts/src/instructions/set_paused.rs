@@ -0,0 +1,36 @@
+//! SetPaused instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{configs::InvestmentTrustsConfigs, role::Role},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetPausedParams)]
+pub struct SetPaused<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Pauser) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPausedParams {
+    pub paused: bool, // true halts trading, false resumes it
+}
+
+pub fn set_paused(ctx: Context<SetPaused>, params: &SetPausedParams) -> Result<()> {
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    investment_trusts_configs.paused = params.paused;
+
+    Ok(())
+}
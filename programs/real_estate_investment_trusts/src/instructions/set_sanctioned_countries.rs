@@ -0,0 +1,55 @@
+//! SetSanctionedCountries instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            configs::{InvestmentTrustsConfigs, MAX_SANCTIONED_COUNTRIES},
+            jurisdiction_policy::country_code,
+            role::Role,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetSanctionedCountriesParams)]
+pub struct SetSanctionedCountries<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Admin) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetSanctionedCountriesParams {
+    pub countries: Vec<String>, // country codes replacing the current list
+}
+
+pub fn set_sanctioned_countries(
+    ctx: Context<SetSanctionedCountries>,
+    params: &SetSanctionedCountriesParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.countries.len() > MAX_SANCTIONED_COUNTRIES {
+        return Err(RealEstateInvestmentTrustsError::TooManyCountries.into());
+    }
+
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    // the new list replaces the old one, unused slots are cleared
+    let mut sanctioned_countries = [[0u8; 3]; MAX_SANCTIONED_COUNTRIES];
+    for (slot, country) in sanctioned_countries.iter_mut().zip(params.countries.iter()) {
+        *slot = country_code(country)?;
+    }
+
+    investment_trusts_configs.sanctioned_countries = sanctioned_countries;
+    investment_trusts_configs.sanctioned_count = params.countries.len() as u8;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! SetSchemeStatus instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            configs::InvestmentTrustsConfigs,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme, role::Role,
+            scheme_status::SchemeStatus,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: SetSchemeStatusParams)]
+pub struct SetSchemeStatus<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::SchemeApprover) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // scheme approver
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetSchemeStatusParams {
+    pub status: SchemeStatus, // status to move the scheme to, see SchemeStatus::transition
+}
+
+pub fn set_scheme_status(
+    ctx: Context<SetSchemeStatus>,
+    params: &SetSchemeStatusParams,
+) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+
+    real_estate_investment_trust_scheme.status =
+        SchemeStatus::transition(real_estate_investment_trust_scheme.status, params.status)?;
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+//! SettleRedemption instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{quote_to_base_units, quote_value, units_to_base_units},
+        state::{
+            deposit_base::DepositBase,
+            holding::Holding,
+            investor::Investor,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            redemption::{RedemptionRequest, RedemptionWindow},
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        burn, transfer_checked, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SettleRedemption<'info> {
+    #[account(mut, has_one = deposit_account,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(mut,
+        constraint = redemption_window.index == redemption_request.window @ RealEstateInvestmentTrustsError::RedemptionWindowMismatch,
+        seeds = [
+            b"redemption-window",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            redemption_window.index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub redemption_window: Account<'info, RedemptionWindow>,
+    #[account(mut,
+        constraint = redemption_request.units > 0 @ RealEstateInvestmentTrustsError::NothingToRedeem,
+        seeds = [b"redemption", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    #[account(mut, address = redemption_request.investor)]
+    pub investor: Account<'info, Investor>,
+    #[account(mut,
+        constraint = !holding.frozen @ RealEstateInvestmentTrustsError::HoldingFrozen,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Account<'info, Holding>,
+    #[account(mut, address = real_estate_investment_trust_scheme.redemption_escrow)]
+    pub redemption_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: InterfaceAccount<'info, Mint>,
+    // scheme's settlement vault
+    #[account(mut,
+        address = real_estate_investment_trust_scheme.settlement_vault,
+        token::authority = treasury_vault
+    )]
+    pub sender_tokens: InterfaceAccount<'info, TokenAccount>,
+    // investor's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = redemption_request.owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub recipient_tokens: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    #[account(
+        constraint = deposit_account.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [b"deposit-base", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub deposit_account: Account<'info, DepositBase>,
+    #[account(seeds = [b"auth", deposit_account.key().as_ref()], bump)]
+    /// CHECK: no need to check this.
+    pub pda_auth: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"treasury-vault", pda_auth.key().as_ref()], bump)]
+    pub treasury_vault: SystemAccount<'info>,
+    // any signer may settle a request against its closed window
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn settle_redemption(ctx: Context<SettleRedemption>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let redemption_window = &mut ctx.accounts.redemption_window;
+    let redemption_request = &mut ctx.accounts.redemption_request;
+    let investor = &mut ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+    let decimals = ctx.accounts.mint_token.decimals;
+
+    // pro rata share of the window's gate, the rest carries over to the next window
+    let unit_investment_trusts = redemption_window
+        .fill(redemption_request.units)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    redemption_request.units = redemption_request
+        .units
+        .checked_sub(unit_investment_trusts)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    redemption_request.window = redemption_request
+        .window
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    if unit_investment_trusts == 0 {
+        return Ok(());
+    }
+
+    // holding, the funds released are the cost paid for the units redeemed
+    holding.queued_units = holding
+        .queued_units
+        .checked_sub(unit_investment_trusts)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    let released_funds = holding.debit(
+        real_estate_investment_trust_scheme,
+        investor,
+        unit_investment_trusts,
+    )?;
+
+    // Deduct released_funds from investor_funds_raised
+    real_estate_investment_trust_scheme.investor_funds_raised = real_estate_investment_trust_scheme
+        .investor_funds_raised
+        .checked_sub(released_funds)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // Deduct redeemed unit_investment_trusts from the scheme's issued units
+    real_estate_investment_trust_scheme.total_units_investment_trusts =
+        real_estate_investment_trust_scheme
+            .total_units_investment_trusts
+            .checked_sub(unit_investment_trusts)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // units are redeemed at the price the window closed at
+    let proceeds = quote_value(unit_investment_trusts, redemption_window.unit_cost)?;
+    let proceeds = quote_to_base_units(proceeds, decimals)?;
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    // redeemed units are retired so the mint supply keeps matching the issued units
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    burn(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.unit_mint.to_account_info(),
+                from: ctx.accounts.redemption_escrow.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        units,
+    )?;
+
+    // Transfer funds from treasury vault to recipient
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.sender_tokens.to_account_info(),
+        mint: ctx.accounts.mint_token.to_account_info(),
+        to: ctx.accounts.recipient_tokens.to_account_info(),
+        authority: ctx.accounts.treasury_vault.to_account_info(),
+    };
+
+    let pda_auth = ctx.accounts.pda_auth.key();
+    let seeds = &[
+        b"treasury-vault",
+        pda_auth.as_ref(),
+        &[ctx.bumps.treasury_vault],
+    ];
+
+    let signer = &[&seeds[..]];
+
+    let cpi = CpiContext::new_with_signer(
+        ctx.accounts.quote_token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+
+    transfer_checked(cpi, proceeds, decimals)?;
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+//! SubscribeOffering instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::quote_to_base_units,
+        state::{
+            configs::InvestmentTrustsConfigs, investor::Investor,
+            jurisdiction_policy::JurisdictionPolicy, offering::OfferingStatus,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus, subscription::Subscription,
+        },
+        token::with_transfer_fee,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: SubscribeOfferingParams)]
+pub struct SubscribeOffering<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.offering.status == OfferingStatus::Open @ RealEstateInvestmentTrustsError::OfferingNotOpen,
+        constraint = real_estate_investment_trust_scheme.non_compliant_epochs == 0 @ RealEstateInvestmentTrustsError::SchemeNonCompliant,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // required once the scheme has a jurisdiction policy
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Option<Account<'info, JurisdictionPolicy>>,
+    #[account(has_one = owner,
+        constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub investor: Account<'info, Investor>,
+    // init_if_needed lets an investor top up an existing subscription
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [b"subscription", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    // investor's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub sender_tokens: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = real_estate_investment_trust_scheme.offering.escrow)]
+    pub offering_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SubscribeOfferingParams {
+    pub amount: u64, // funds to commit, fixed-point quote value
+}
+
+pub fn subscribe_offering(
+    ctx: Context<SubscribeOffering>,
+    params: &SubscribeOfferingParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let subscription = &mut ctx.accounts.subscription;
+    let _amount = params.amount;
+
+    // subscriptions are taken once the scheme is approved
+    match real_estate_investment_trust_scheme.status {
+        SchemeStatus::Approved | SchemeStatus::Listed => {}
+        _ => return Err(RealEstateInvestmentTrustsError::SchemeNotListed.into()),
+    }
+
+    // only eligible investors may subscribe
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.investor.check_eligibility(
+        &ctx.accounts.investment_trusts_configs,
+        real_estate_investment_trust_scheme,
+        ctx.accounts.jurisdiction_policy.as_deref(),
+        now,
+    )?;
+
+    let offering = &mut real_estate_investment_trust_scheme.offering;
+    if now < offering.start_time || now >= offering.end_time {
+        return Err(RealEstateInvestmentTrustsError::OfferingNotOpen.into());
+    }
+
+    if _amount == 0 || _amount < offering.min_ticket {
+        return Err(RealEstateInvestmentTrustsError::BelowMinimumTicket.into());
+    }
+
+    // every subscription must buy whole units at the offering price
+    if _amount
+        .checked_rem(offering.price)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?
+        != 0
+    {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    let offering = &mut real_estate_investment_trust_scheme.offering;
+    offering.raised = offering
+        .raised
+        .checked_add(_amount)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    if offering.raised > offering.hard_cap {
+        return Err(RealEstateInvestmentTrustsError::HardCapExceeded.into());
+    }
+
+    // subscription
+    if !subscription.is_initialized {
+        subscription.real_estate_investment_trust_scheme =
+            real_estate_investment_trust_scheme.key();
+        subscription.investor = ctx.accounts.investor.key();
+        subscription.owner = *ctx.accounts.owner.key;
+        subscription.is_initialized = true;
+    }
+
+    subscription.amount = subscription
+        .amount
+        .checked_add(_amount)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // lets get the amount in the smallest unit of the quote mint
+    let _amount = quote_to_base_units(_amount, ctx.accounts.mint_token.decimals)?;
+
+    // the investor covers any transfer fee so the escrow holds the full subscription
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.sender_tokens.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.offering_escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        with_transfer_fee(&ctx.accounts.mint_token, _amount)?,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    Ok(())
+}
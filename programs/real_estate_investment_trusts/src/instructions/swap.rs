@@ -0,0 +1,282 @@
+//! Swap instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::{base_units_to_quote, quote_to_base_units, units_to_base_units},
+        state::{
+            configs::InvestmentTrustsConfigs,
+            holding::Holding,
+            investor::Investor,
+            jurisdiction_policy::JurisdictionPolicy,
+            pool::{Pool, SwapDirection},
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+        token::{transfer_checked_with_hook, unit_transfer_authority, with_transfer_fee},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: SwapParams)]
+pub struct Swap<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Box<Account<'info, InvestmentTrustsConfigs>>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Box<Account<'info, RealEstateInvestmentTrustScheme>>,
+    // required once the scheme has a jurisdiction policy
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Option<Account<'info, JurisdictionPolicy>>,
+    #[account(mut, has_one = unit_vault, has_one = quote_vault,
+        seeds = [b"pool", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut, has_one = owner,
+        constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub investor: Box<Account<'info, Investor>>,
+    // init_if_needed creates the holding on the investor's first purchase in this scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Holding::INIT_SPACE,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Box<Account<'info, Holding>>,
+    #[account(mut)]
+    pub unit_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    // investor's unit token account
+    #[account(mut,
+        associated_token::mint = unit_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub investor_units: Box<InterfaceAccount<'info, TokenAccount>>,
+    // investor's quote token account
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = quote_token_program
+    )]
+    pub investor_tokens: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: Box<InterfaceAccount<'info, Mint>>,
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub unit_mint: Box<InterfaceAccount<'info, Mint>>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // token program of the unit mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // token program of the quote mint, which may differ from the unit mint's
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associate_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapParams {
+    pub direction: SwapDirection,
+    pub units: u64,       // whole units of investment trusts bought or sold
+    pub quote_limit: u64, // most paid when buying, least received when selling, fixed-point quote value
+}
+
+pub fn swap<'info>(
+    ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+    params: &SwapParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.units == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let pool = &mut ctx.accounts.pool;
+    let investor = &mut ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let token_program = &ctx.accounts.token_program;
+    let decimals = ctx.accounts.mint_token.decimals;
+    let unit_investment_trusts = params.units;
+    let quote_limit = quote_to_base_units(params.quote_limit, decimals)?;
+
+    // the pool is held to a band around the latest valuation, which must not be stale
+    let clock = Clock::get()?;
+    real_estate_investment_trust_scheme.check_valuation_fresh(
+        ctx.accounts.investment_trusts_configs.max_nav_age,
+        clock.unix_timestamp,
+    )?;
+
+    investor.check_eligibility(
+        &ctx.accounts.investment_trusts_configs,
+        real_estate_investment_trust_scheme,
+        ctx.accounts.jurisdiction_policy.as_deref(),
+        clock.unix_timestamp,
+    )?;
+
+    holding.initialize(
+        real_estate_investment_trust_scheme.key(),
+        investor.key(),
+        *ctx.accounts.owner.key,
+        clock.slot,
+    );
+
+    let amount = match params.direction {
+        SwapDirection::BuyUnits => {
+            let (amount, _fee) = pool.buy_units(unit_investment_trusts)?;
+            if amount > quote_limit {
+                return Err(RealEstateInvestmentTrustsError::SlippageExceeded.into());
+            }
+
+            let cost = base_units_to_quote(amount, decimals)?;
+            holding.credit(
+                real_estate_investment_trust_scheme,
+                investor,
+                unit_investment_trusts,
+                cost,
+            )?;
+
+            // every purchase restarts the lock-up on the position
+            holding.locked_until = clock
+                .unix_timestamp
+                .checked_add(real_estate_investment_trust_scheme.lock_up_period)
+                .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+            amount
+        }
+        SwapDirection::SellUnits => {
+            let (amount, _fee) = pool.sell_units(unit_investment_trusts)?;
+            if amount < quote_limit {
+                return Err(RealEstateInvestmentTrustsError::SlippageExceeded.into());
+            }
+
+            // units cannot be sold during the lock-up that follows a purchase
+            if clock.unix_timestamp < holding.locked_until {
+                return Err(RealEstateInvestmentTrustsError::HoldingLocked.into());
+            }
+
+            if unit_investment_trusts > holding.free_units()? {
+                return Err(RealEstateInvestmentTrustsError::InsufficientUnits.into());
+            }
+
+            holding.debit(
+                real_estate_investment_trust_scheme,
+                investor,
+                unit_investment_trusts,
+            )?;
+
+            amount
+        }
+    };
+    real_estate_investment_trust_scheme.pooled_units = pool.unit_reserve;
+
+    // the trade may not push the pool price too far from NAV
+    pool.check_price_band(quote_to_base_units(
+        real_estate_investment_trust_scheme.unit_cost_of_investment_trusts,
+        decimals,
+    )?)?;
+
+    let units = units_to_base_units(
+        unit_investment_trusts,
+        real_estate_investment_trust_scheme.decimals,
+    )?;
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    match params.direction {
+        SwapDirection::BuyUnits => {
+            // the investor covers any transfer fee so the pool holds the full amount
+            transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.quote_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.investor_tokens.to_account_info(),
+                        mint: ctx.accounts.mint_token.to_account_info(),
+                        to: ctx.accounts.quote_vault.to_account_info(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                with_transfer_fee(&ctx.accounts.mint_token, amount)?,
+                decimals,
+            )?;
+
+            transfer_checked_with_hook(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.unit_vault.to_account_info(),
+                        mint: ctx.accounts.unit_mint.to_account_info(),
+                        to: ctx.accounts.investor_units.to_account_info(),
+                        authority: real_estate_investment_trust_scheme.to_account_info(),
+                    },
+                    signer,
+                )
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+                units,
+                real_estate_investment_trust_scheme.decimals,
+            )?;
+        }
+        SwapDirection::SellUnits => {
+            transfer_checked_with_hook(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.investor_units.to_account_info(),
+                        mint: ctx.accounts.unit_mint.to_account_info(),
+                        to: ctx.accounts.unit_vault.to_account_info(),
+                        authority: unit_transfer_authority(
+                            real_estate_investment_trust_scheme,
+                            &ctx.accounts.owner.to_account_info(),
+                        ),
+                    },
+                    signer,
+                )
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+                units,
+                real_estate_investment_trust_scheme.decimals,
+            )?;
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.quote_token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.quote_vault.to_account_info(),
+                        mint: ctx.accounts.mint_token.to_account_info(),
+                        to: ctx.accounts.investor_tokens.to_account_info(),
+                        authority: real_estate_investment_trust_scheme.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+                decimals,
+            )?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! SweepDistribution instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            distribution::Distribution,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{
+        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SweepDistribution<'info> {
+    #[account(mut, has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // distributions are swept in the order they expire, i.e declaration order
+    #[account(mut,
+        constraint = distribution.index == real_estate_investment_trust_scheme.swept_distributions @ RealEstateInvestmentTrustsError::DistributionNotSweepable,
+        seeds = [
+            b"distribution",
+            real_estate_investment_trust_scheme.key().as_ref(),
+            distribution.index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+    #[account(mut, address = real_estate_investment_trust_scheme.distribution_vault)]
+    pub distribution_vault: InterfaceAccount<'info, TokenAccount>,
+    // scheme's settlement vault
+    #[account(mut, address = real_estate_investment_trust_scheme.settlement_vault)]
+    pub settlement_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // trust scheme promoter
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn sweep_distribution(ctx: Context<SweepDistribution>) -> Result<()> {
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let distribution = &mut ctx.accounts.distribution;
+
+    if Clock::get()?.unix_timestamp < distribution.expires_at {
+        return Err(RealEstateInvestmentTrustsError::DistributionNotExpired.into());
+    }
+
+    // holders settle against the swept accumulator and forfeit their share
+    real_estate_investment_trust_scheme.swept_reward_per_unit = distribution.reward_per_unit;
+    real_estate_investment_trust_scheme.swept_distributions = distribution
+        .index
+        .checked_add(1)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    real_estate_investment_trust_scheme.unswept_distribution_amount =
+        real_estate_investment_trust_scheme
+            .unswept_distribution_amount
+            .checked_sub(distribution.amount)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    // the vault keeps enough for the distributions not swept yet, the rest is
+    // unclaimed funds, rounding dust and forfeited accruals of this and earlier ones
+    let amount = ctx
+        .accounts
+        .distribution_vault
+        .amount
+        .saturating_sub(real_estate_investment_trust_scheme.unswept_distribution_amount);
+    distribution.swept = amount;
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.distribution_vault.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
+                to: ctx.accounts.settlement_vault.to_account_info(),
+                authority: real_estate_investment_trust_scheme.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        ctx.accounts.mint_token.decimals,
+    )?;
+
+    Ok(())
+}
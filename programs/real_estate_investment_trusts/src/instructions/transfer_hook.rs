@@ -0,0 +1,150 @@
+//! TransferHook instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            configs::InvestmentTrustsConfigs, investor::Investor,
+            jurisdiction_policy::JurisdictionPolicy,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_2022::spl_token_2022::{
+            self,
+            extension::{
+                transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
+            },
+        },
+        token_interface::{Mint, TokenAccount},
+    },
+};
+
+// account order is fixed by the transfer hook interface, the accounts after
+// extra_account_meta_list are resolved from it, see extra_account_metas
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint_token)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint_token)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    ///CHECK: owner or delegate of the source token account, checked by the token program
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"extra-account-metas", mint_token.key().as_ref()],
+        bump
+    )]
+    ///CHECK: validation account read by the token program
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    #[account(
+        constraint = real_estate_investment_trust_scheme.unit_mint == mint_token.key() @ RealEstateInvestmentTrustsError::UnitMintMismatch,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(
+        seeds = [b"investment-trusts-configs"],
+        bump
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    ///CHECK: only read when the scheme has a jurisdiction policy
+    pub jurisdiction_policy: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"investor", source_token.owner.as_ref()],
+        bump
+    )]
+    ///CHECK: kept in the account layout, the sender is checked by the program
+    pub source_investor: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"investor", destination_token.owner.as_ref()],
+        bump
+    )]
+    ///CHECK: not registered when the destination is a scheme escrow
+    pub destination_investor: UncheckedAccount<'info>,
+}
+
+pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+    msg!("Validate inputs");
+    // the hook is only meaningful inside a transfer of the unit mint
+    check_transferring(&ctx.accounts.source_token)?;
+
+    let real_estate_investment_trust_scheme = &ctx.accounts.real_estate_investment_trust_scheme;
+    let investment_trusts_configs = &ctx.accounts.investment_trusts_configs;
+    let scheme_key = real_estate_investment_trust_scheme.key();
+
+    // no units move while the program is paused, a suspended scheme still
+    // returns escrowed units and allows compliance actions
+    if investment_trusts_configs.paused {
+        return Err(RealEstateInvestmentTrustsError::ProgramPaused.into());
+    }
+    match real_estate_investment_trust_scheme.status {
+        SchemeStatus::Listed | SchemeStatus::Suspended => {}
+        _ => return Err(RealEstateInvestmentTrustsError::SchemeNotListed.into()),
+    }
+
+    let jurisdiction_policy = if real_estate_investment_trust_scheme.has_jurisdiction_policy {
+        let policy = &ctx.accounts.jurisdiction_policy;
+        if policy.owner != &crate::ID {
+            return Err(RealEstateInvestmentTrustsError::JurisdictionPolicyMissing.into());
+        }
+        Some(JurisdictionPolicy::try_deserialize(
+            &mut &policy.try_borrow_data()?[..],
+        )?)
+    } else {
+        None
+    };
+
+    // units only move through the program, which signs as permanent delegate,
+    // so a holder cannot bypass the holding records with a wallet transfer
+    if ctx.accounts.owner.key() != scheme_key {
+        return Err(RealEstateInvestmentTrustsError::TransferNotAuthorized.into());
+    }
+
+    // the sender is checked by the instruction moving the units, units in
+    // scheme escrows (asks, redemptions, pool) move without an investor and
+    // escrowed units returned to their holder are not a new purchase
+    let destination_owner = ctx.accounts.destination_token.owner;
+    let returning_units = ctx.accounts.source_token.owner == scheme_key
+        && real_estate_investment_trust_scheme.returning_units;
+    if destination_owner != scheme_key && !returning_units {
+        let investor = &ctx.accounts.destination_investor;
+        if investor.owner != &crate::ID || investor.data_is_empty() {
+            return Err(RealEstateInvestmentTrustsError::InvestorNotRegistered.into());
+        }
+
+        let investor = Investor::try_deserialize(&mut &investor.try_borrow_data()?[..])?;
+        if !investor.active {
+            return Err(RealEstateInvestmentTrustsError::InvalidInvestorStatus.into());
+        }
+
+        // the recipient must be eligible for this scheme
+        investor.check_eligibility(
+            investment_trusts_configs,
+            real_estate_investment_trust_scheme,
+            jurisdiction_policy.as_ref(),
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
+    Ok(())
+}
+
+// the token program flags the source account while it invokes the hook, which
+// stops the hook being called directly to pass for a transfer
+fn check_transferring(source_token: &InterfaceAccount<TokenAccount>) -> Result<()> {
+    let source_token = source_token.to_account_info();
+    let data = source_token.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    let extension = account.get_extension::<TransferHookAccount>()?;
+
+    if !bool::from(extension.transferring) {
+        return Err(RealEstateInvestmentTrustsError::NotTransferring.into());
+    }
+
+    Ok(())
+}
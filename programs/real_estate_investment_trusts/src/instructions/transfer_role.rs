@@ -0,0 +1,61 @@
+//! TransferRole instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{configs::InvestmentTrustsConfigs, role::Role},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: TransferRoleParams)]
+pub struct TransferRole<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Admin) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransferRoleParams {
+    pub role: Role,            // role to hand over
+    pub authority: Pubkey,     // publickey currently holding the role
+    pub new_authority: Pubkey, // publickey receiving the role
+}
+
+pub fn transfer_role(ctx: Context<TransferRole>, params: &TransferRoleParams) -> Result<()> {
+    msg!("Validate inputs");
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    if !investment_trusts_configs.has_role(&params.authority, params.role) {
+        return Err(RealEstateInvestmentTrustsError::RoleNotFound.into());
+    }
+
+    if investment_trusts_configs.has_role(&params.new_authority, params.role) {
+        return Err(RealEstateInvestmentTrustsError::RoleAlreadyGranted.into());
+    }
+
+    match params.role {
+        // the new admin takes over once it accepts, so a wrong key cannot lock out the protocol
+        Role::Admin => investment_trusts_configs.pending_admin = params.new_authority,
+        _ => {
+            let assignment = investment_trusts_configs
+                .roles
+                .iter_mut()
+                .find(|assignment| {
+                    assignment.role == params.role && assignment.authority == params.authority
+                })
+                .ok_or(RealEstateInvestmentTrustsError::RoleNotFound)?;
+
+            assignment.authority = params.new_authority;
+        }
+    }
+
+    Ok(())
+}
@@ -3,72 +3,176 @@
 use {
     crate::{
         error::RealEstateInvestmentTrustsError,
-        state::real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        math::units_to_base_units,
+        state::{
+            configs::InvestmentTrustsConfigs,
+            holding::{move_units, Holding},
+            investor::Investor,
+            jurisdiction_policy::JurisdictionPolicy,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+            scheme_status::SchemeStatus,
+        },
+        token::{transfer_checked_with_hook, unit_transfer_authority},
     },
-    anchor_lang::{prelude::*, system_program},
+    anchor_lang::prelude::*,
     anchor_spl::{
-        associated_token,
         associated_token::AssociatedToken,
-        token::{transfer, Token, TokenAccount, Transfer},
+        token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
     },
 };
 
 #[derive(Accounts)]
 #[instruction(params: TransferTokenParams)]
 pub struct TransferToken<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = !investment_trusts_configs.paused @ RealEstateInvestmentTrustsError::ProgramPaused
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
     #[account(mut,
-        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status == SchemeStatus::Listed @ RealEstateInvestmentTrustsError::SchemeNotListed,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
     )]
     pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
-    #[account(mut)]
-    pub from_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub to_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub mint_token: Signer<'info>,
+    // required once the scheme has a jurisdiction policy
+    #[account(
+        seeds = [b"jurisdiction-policy", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub jurisdiction_policy: Option<Account<'info, JurisdictionPolicy>>,
+    #[account(mut,
+        associated_token::mint = mint_token,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub from_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, token::mint = mint_token)]
+    pub to_account: InterfaceAccount<'info, TokenAccount>,
+    // sender
+    #[account(mut, has_one = owner,
+        constraint = investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub investor: Box<Account<'info, Investor>>,
+    #[account(mut,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub holding: Box<Account<'info, Holding>>,
+    // units may only be transferred to another eligible investor
+    #[account(mut,
+        constraint = recipient_investor.key() != investor.key() @ RealEstateInvestmentTrustsError::InvalidTransferRecipient,
+        constraint = recipient_investor.owner == to_account.owner @ RealEstateInvestmentTrustsError::InvestorMismatch,
+        constraint = recipient_investor.active @ RealEstateInvestmentTrustsError::InvalidInvestorStatus
+    )]
+    pub recipient_investor: Box<Account<'info, Investor>>,
+    // init_if_needed creates the holding when the recipient is new to the scheme
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Holding::INIT_SPACE,
+        seeds = [b"holding", real_estate_investment_trust_scheme.key().as_ref(), recipient_investor.key().as_ref()],
+        bump
+    )]
+    pub recipient_holding: Box<Account<'info, Holding>>,
+    // units of the addressed scheme
+    #[account(address = real_estate_investment_trust_scheme.unit_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
     // mut makes it changeble (mutable)
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associate_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct TransferTokenParams {
-    pub amount: u32,
+    pub amount: u64, // whole units of investment trusts to transfer
 }
 
-pub fn transfer_token(ctx: Context<TransferToken>, params: &TransferTokenParams) -> Result<()> {
+pub fn transfer_token<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferToken<'info>>,
+    params: &TransferTokenParams,
+) -> Result<()> {
     msg!("Validate inputs");
     if params.amount == 0 {
         return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
     }
 
-    let real_estate_investment_trust_scheme = &ctx.accounts.real_estate_investment_trust_scheme;
-    let decimals = real_estate_investment_trust_scheme.decimals;
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    let investor = &mut ctx.accounts.investor;
+    let holding = &mut ctx.accounts.holding;
+    let recipient_investor = &mut ctx.accounts.recipient_investor;
+    let recipient_holding = &mut ctx.accounts.recipient_holding;
     let _amount = params.amount;
 
-    let base: u32 = 10;
-    let exponent = real_estate_investment_trust_scheme.decimals as u32;
+    // both sides of the transfer must be eligible for the scheme
+    let clock = Clock::get()?;
+    for party in [&investor, &recipient_investor] {
+        party.check_eligibility(
+            &ctx.accounts.investment_trusts_configs,
+            real_estate_investment_trust_scheme,
+            ctx.accounts.jurisdiction_policy.as_deref(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // units cannot be transferred during the lock-up that follows a purchase
+    if clock.unix_timestamp < holding.locked_until {
+        return Err(RealEstateInvestmentTrustsError::HoldingLocked.into());
+    }
+
+    // units already queued or offered in an ask cannot be transferred
+    if _amount > holding.free_units()? {
+        return Err(RealEstateInvestmentTrustsError::InsufficientUnits.into());
+    }
+
+    recipient_holding.initialize(
+        real_estate_investment_trust_scheme.key(),
+        recipient_investor.key(),
+        recipient_investor.owner,
+        clock.slot,
+    );
+    move_units(
+        real_estate_investment_trust_scheme,
+        holding,
+        investor,
+        recipient_holding,
+        recipient_investor,
+        _amount,
+    )?;
+
+    // lets get the amount in the smallest unit of the unit mint
+    let _amount = units_to_base_units(_amount, real_estate_investment_trust_scheme.decimals)?;
 
-    // lets get the amount in decimal format
-    // 10 ** 9 * 3(base 10, 9 decimals, 3 amount), // 3 amount of token to transfer (in smallest unit i.e 9 decimals)
-    let result = (base).pow(exponent);
-    let _amount = (_amount as u64)
-        .checked_mul(result as u64)
-        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
 
-    transfer(
-        CpiContext::new(
+    // remaining accounts carry the transfer hook accounts of a Token-2022 unit mint
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                authority: ctx.accounts.owner.to_account_info(),
+            TransferChecked {
+                authority: unit_transfer_authority(
+                    real_estate_investment_trust_scheme,
+                    &ctx.accounts.owner.to_account_info(),
+                ),
                 from: ctx.accounts.from_account.to_account_info(),
+                mint: ctx.accounts.mint_token.to_account_info(),
                 to: ctx.accounts.to_account.to_account_info(),
             },
-        ),
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         _amount,
+        ctx.accounts.mint_token.decimals,
     )?;
 
     Ok(())
@@ -0,0 +1,57 @@
+//! UpdateConfigs instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::BASIS_POINTS,
+        state::{configs::InvestmentTrustsConfigs, role::Role},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(params: UpdateConfigsParams)]
+pub struct UpdateConfigs<'info> {
+    #[account(mut,
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Admin) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    // mut makes it changeble (mutable)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateConfigsParams {
+    pub max_nav_age: i64, // seconds a valuation stays usable for trading, 0 disables the check
+    pub min_payout_ratio_bps: u16, // share of reported income an income REIT must distribute
+    pub payout_grace_period: i64, // seconds after an income period to meet the payout ratio
+    pub redemption_window_length: i64, // seconds a redemption window stays open
+    pub redemption_gate_bps: u16, // share of net asset value redeemable per window, 0 disables the gate
+}
+
+pub fn update_configs(ctx: Context<UpdateConfigs>, params: &UpdateConfigsParams) -> Result<()> {
+    msg!("Validate inputs");
+    if params.max_nav_age < 0
+        || params.payout_grace_period < 0
+        || params.redemption_window_length < 0
+    {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
+    }
+
+    if params.min_payout_ratio_bps > BASIS_POINTS || params.redemption_gate_bps > BASIS_POINTS {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
+    }
+
+    let investment_trusts_configs = &mut ctx.accounts.investment_trusts_configs;
+
+    investment_trusts_configs.max_nav_age = params.max_nav_age;
+    investment_trusts_configs.min_payout_ratio_bps = params.min_payout_ratio_bps;
+    investment_trusts_configs.payout_grace_period = params.payout_grace_period;
+    investment_trusts_configs.redemption_window_length = params.redemption_window_length;
+    investment_trusts_configs.redemption_gate_bps = params.redemption_gate_bps;
+
+    Ok(())
+}
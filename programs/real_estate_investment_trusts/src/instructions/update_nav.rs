@@ -0,0 +1,69 @@
+//! UpdateNav instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        math::quote_to_base_units,
+        state::{
+            configs::InvestmentTrustsConfigs,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme, role::Role,
+            scheme_status::SchemeStatus,
+        },
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+};
+
+#[derive(Accounts)]
+#[instruction(params: UpdateNavParams)]
+pub struct UpdateNav<'info> {
+    #[account(
+        constraint = investment_trusts_configs.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = investment_trusts_configs.has_role(owner.key, Role::Valuer) @ RealEstateInvestmentTrustsError::Unauthorized
+    )]
+    pub investment_trusts_configs: Account<'info, InvestmentTrustsConfigs>,
+    #[account(mut,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.status != SchemeStatus::Delisted @ RealEstateInvestmentTrustsError::InvalidSchemeStatusTransition,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    #[account(address = real_estate_investment_trust_scheme.quote_mint)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+    // valuer
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateNavParams {
+    pub net_asset_value: u64, // net asset value of the scheme, fixed-point quote value
+    pub nav_per_unit: u64,    // net asset value per unit, fixed-point quote value
+    pub appraisal_hash: [u8; 32], // hash of the appraisal document behind the valuation
+}
+
+pub fn update_nav(ctx: Context<UpdateNav>, params: &UpdateNavParams) -> Result<()> {
+    msg!("Validate inputs");
+    if params.nav_per_unit == 0 {
+        return Err(RealEstateInvestmentTrustsError::InvalidNumeric.into());
+    }
+
+    // units must stay payable exactly in the quote mint's smallest unit
+    quote_to_base_units(params.nav_per_unit, ctx.accounts.quote_mint.decimals)?;
+
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+
+    // buy and sell price units at the latest valuation
+    real_estate_investment_trust_scheme.net_asset_value = params.net_asset_value;
+    real_estate_investment_trust_scheme.unit_cost_of_investment_trusts = params.nav_per_unit;
+    real_estate_investment_trust_scheme.appraisal_hash = params.appraisal_hash;
+    real_estate_investment_trust_scheme.valuation_timestamp = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! UpdateTokenMetadata instruction handler
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::{
+            issuer_registry_entry::IssuerRegistryEntry,
+            real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+        },
+        token::update_token_metadata_fields,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_2022_extensions::spl_token_metadata_interface::state::Field,
+        token_interface::{Mint, TokenInterface},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: UpdateTokenMetadataParams)]
+pub struct UpdateTokenMetadata<'info> {
+    #[account(mut, has_one = owner,
+        constraint = real_estate_investment_trust_scheme.is_initialized @ RealEstateInvestmentTrustsError::AccountNotInitialized,
+        constraint = real_estate_investment_trust_scheme.unit_metadata @ RealEstateInvestmentTrustsError::TokenMetadataNotEnabled,
+        seeds = [
+            b"investment-trust-scheme",
+            real_estate_investment_trust_scheme.owner.as_ref(),
+            real_estate_investment_trust_scheme.scheme_id.to_le_bytes().as_ref(),
+        ],
+        bump = real_estate_investment_trust_scheme.bump,
+    )]
+    pub real_estate_investment_trust_scheme: Account<'info, RealEstateInvestmentTrustScheme>,
+    // the registry keeps the same issuer details as the scheme
+    #[account(mut,
+        seeds = [b"issuer-registry", real_estate_investment_trust_scheme.key().as_ref()],
+        bump
+    )]
+    pub issuer_registry_entry: Account<'info, IssuerRegistryEntry>,
+    #[account(mut, address = real_estate_investment_trust_scheme.unit_mint)]
+    pub mint_token: InterfaceAccount<'info, Mint>,
+    // trust scheme promoter, pays for any growth of the metadata
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTokenMetadataParams {
+    pub name: String, // name of the scheme, shown as the unit mint's name
+    pub uri: String,  // link to the scheme's current disclosures
+}
+
+// name length
+const NAME_LENGTH: usize = 30;
+// uri length
+const URI_LENGTH: usize = 200;
+
+pub fn update_token_metadata(
+    ctx: Context<UpdateTokenMetadata>,
+    params: &UpdateTokenMetadataParams,
+) -> Result<()> {
+    msg!("Validate inputs");
+    if params.name.is_empty() || params.name.len() > NAME_LENGTH {
+        return Err(RealEstateInvestmentTrustsError::InvalidNameLength.into());
+    }
+
+    if params.uri.len() > URI_LENGTH {
+        return Err(RealEstateInvestmentTrustsError::InvalidUriLength.into());
+    }
+
+    // the issuer, REIT type and listing date stay as registered, so the
+    // symbol and the other metadata fields are left untouched
+    let real_estate_investment_trust_scheme = &mut ctx.accounts.real_estate_investment_trust_scheme;
+    real_estate_investment_trust_scheme.issuer.name = params.name.to_string();
+    ctx.accounts.issuer_registry_entry.issuer.name = params.name.to_string();
+
+    let fields = vec![
+        (Field::Name, params.name.to_string()),
+        (Field::Uri, params.uri.to_string()),
+    ];
+
+    // the scheme is the metadata update authority
+    let scheme_signer = real_estate_investment_trust_scheme.signer();
+    let seeds = scheme_signer.seeds();
+    let signer = &[&seeds[..]];
+
+    update_token_metadata_fields(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint_token.to_account_info(),
+        &real_estate_investment_trust_scheme.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        fields,
+        signer,
+    )?;
+
+    Ok(())
+}
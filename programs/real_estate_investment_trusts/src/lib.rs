@@ -1,8 +1,11 @@
 //! real_estate_investment_trusts program entrypoint
 
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod math;
 pub mod state;
+pub mod token;
 
 use {anchor_lang::prelude::*, instructions::*};
 
@@ -17,7 +20,88 @@ pub mod real_estate_investment_trusts {
         instructions::init(ctx, &params)
     }
 
-    // public instructions
+    pub fn grant_role(ctx: Context<GrantRole>, params: GrantRoleParams) -> Result<()> {
+        instructions::grant_role(ctx, &params)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, params: RevokeRoleParams) -> Result<()> {
+        instructions::revoke_role(ctx, &params)
+    }
+
+    pub fn transfer_role(ctx: Context<TransferRole>, params: TransferRoleParams) -> Result<()> {
+        instructions::transfer_role(ctx, &params)
+    }
+
+    pub fn accept_admin_role(ctx: Context<AcceptAdminRole>) -> Result<()> {
+        instructions::accept_admin_role(ctx)
+    }
+
+    pub fn update_configs(ctx: Context<UpdateConfigs>, params: UpdateConfigsParams) -> Result<()> {
+        instructions::update_configs(ctx, &params)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, params: SetPausedParams) -> Result<()> {
+        instructions::set_paused(ctx, &params)
+    }
+
+    pub fn set_sanctioned_countries(
+        ctx: Context<SetSanctionedCountries>,
+        params: SetSanctionedCountriesParams,
+    ) -> Result<()> {
+        instructions::set_sanctioned_countries(ctx, &params)
+    }
+
+    // scheme approver instructions
+    pub fn set_scheme_status(
+        ctx: Context<SetSchemeStatus>,
+        params: SetSchemeStatusParams,
+    ) -> Result<()> {
+        instructions::set_scheme_status(ctx, &params)
+    }
+
+    pub fn deregister_issuer(ctx: Context<DeregisterIssuer>) -> Result<()> {
+        instructions::deregister_issuer(ctx)
+    }
+
+    // compliance officer instructions
+    pub fn attest_investor(
+        ctx: Context<AttestInvestor>,
+        params: AttestInvestorParams,
+    ) -> Result<()> {
+        instructions::attest_investor(ctx, &params)
+    }
+
+    pub fn set_investor_status(
+        ctx: Context<SetInvestorStatus>,
+        params: SetInvestorStatusParams,
+    ) -> Result<()> {
+        instructions::set_investor_status(ctx, &params)
+    }
+
+    pub fn set_holding_frozen(
+        ctx: Context<SetHoldingFrozen>,
+        params: SetHoldingFrozenParams,
+    ) -> Result<()> {
+        instructions::set_holding_frozen(ctx, &params)
+    }
+
+    pub fn force_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, ForceTransfer<'info>>,
+        params: ForceTransferParams,
+    ) -> Result<()> {
+        instructions::force_transfer(ctx, &params)
+    }
+
+    // valuer instructions
+    pub fn update_nav(ctx: Context<UpdateNav>, params: UpdateNavParams) -> Result<()> {
+        instructions::update_nav(ctx, &params)
+    }
+
+    // scheme promoter instructions
     pub fn register_investment_trust_scheme(
         ctx: Context<RegisterRealEstateInvestmentTrustScheme>,
         params: RegisterRealEstateInvestmentTrustSchemeParams,
@@ -25,6 +109,67 @@ pub mod real_estate_investment_trusts {
         instructions::register_investment_trust_scheme(ctx, &params)
     }
 
+    pub fn create_token(ctx: Context<CreateToken>) -> Result<()> {
+        instructions::create_token(ctx)
+    }
+
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadata>,
+        params: UpdateTokenMetadataParams,
+    ) -> Result<()> {
+        instructions::update_token_metadata(ctx, &params)
+    }
+
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        instructions::initialize_extra_account_meta_list(ctx)
+    }
+
+    pub fn set_jurisdiction_policy(
+        ctx: Context<SetJurisdictionPolicy>,
+        params: SetJurisdictionPolicyParams,
+    ) -> Result<()> {
+        instructions::set_jurisdiction_policy(ctx, &params)
+    }
+
+    pub fn configure_pool(ctx: Context<ConfigurePool>, params: ConfigurePoolParams) -> Result<()> {
+        instructions::configure_pool(ctx, &params)
+    }
+
+    pub fn set_liquidity_provider(
+        ctx: Context<SetLiquidityProvider>,
+        params: SetLiquidityProviderParams,
+    ) -> Result<()> {
+        instructions::set_liquidity_provider(ctx, &params)
+    }
+
+    pub fn configure_offering(
+        ctx: Context<ConfigureOffering>,
+        params: ConfigureOfferingParams,
+    ) -> Result<()> {
+        instructions::configure_offering(ctx, &params)
+    }
+
+    pub fn post_income_epoch(
+        ctx: Context<PostIncomeEpoch>,
+        params: PostIncomeEpochParams,
+    ) -> Result<()> {
+        instructions::post_income_epoch(ctx, &params)
+    }
+
+    pub fn declare_distribution(
+        ctx: Context<DeclareDistribution>,
+        params: DeclareDistributionParams,
+    ) -> Result<()> {
+        instructions::declare_distribution(ctx, &params)
+    }
+
+    pub fn sweep_distribution(ctx: Context<SweepDistribution>) -> Result<()> {
+        instructions::sweep_distribution(ctx)
+    }
+
+    // public instructions
     pub fn register_investor(
         ctx: Context<RegisterInvestor>,
         params: RegisterInvestorParams,
@@ -39,18 +184,108 @@ pub mod real_estate_investment_trusts {
         instructions::buy_investment_trusts(ctx, &params)
     }
 
-    pub fn sell_investment_trusts(
-        ctx: Context<SellInvestmentTrusts>,
+    pub fn sell_investment_trusts<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellInvestmentTrusts<'info>>,
         params: SellInvestmentTrustsParams,
     ) -> Result<()> {
         instructions::sell_investment_trusts(ctx, &params)
     }
 
-    pub fn create_token(ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<()> {
-        instructions::create_token(ctx, &params)
+    pub fn close_redemption_window(ctx: Context<CloseRedemptionWindow>) -> Result<()> {
+        instructions::close_redemption_window(ctx)
+    }
+
+    pub fn settle_redemption(ctx: Context<SettleRedemption>) -> Result<()> {
+        instructions::settle_redemption(ctx)
+    }
+
+    pub fn post_ask<'info>(
+        ctx: Context<'_, '_, '_, 'info, PostAsk<'info>>,
+        params: PostAskParams,
+    ) -> Result<()> {
+        instructions::post_ask(ctx, &params)
+    }
+
+    pub fn fill_ask<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillAsk<'info>>,
+        params: FillAskParams,
+    ) -> Result<()> {
+        instructions::fill_ask(ctx, &params)
+    }
+
+    pub fn cancel_ask<'info>(ctx: Context<'_, '_, '_, 'info, CancelAsk<'info>>) -> Result<()> {
+        instructions::cancel_ask(ctx)
+    }
+
+    pub fn add_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+        params: AddLiquidityParams,
+    ) -> Result<()> {
+        instructions::add_liquidity(ctx, &params)
+    }
+
+    pub fn remove_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+        params: RemoveLiquidityParams,
+    ) -> Result<()> {
+        instructions::remove_liquidity(ctx, &params)
+    }
+
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        params: SwapParams,
+    ) -> Result<()> {
+        instructions::swap(ctx, &params)
+    }
+
+    pub fn subscribe_offering(
+        ctx: Context<SubscribeOffering>,
+        params: SubscribeOfferingParams,
+    ) -> Result<()> {
+        instructions::subscribe_offering(ctx, &params)
+    }
+
+    pub fn finalize_offering(ctx: Context<FinalizeOffering>) -> Result<()> {
+        instructions::finalize_offering(ctx)
+    }
+
+    pub fn claim_subscription(ctx: Context<ClaimSubscription>) -> Result<()> {
+        instructions::claim_subscription(ctx)
+    }
+
+    pub fn refund_subscription(ctx: Context<RefundSubscription>) -> Result<()> {
+        instructions::refund_subscription(ctx)
+    }
+
+    pub fn check_payout_compliance(ctx: Context<CheckPayoutCompliance>) -> Result<()> {
+        instructions::check_payout_compliance(ctx)
     }
 
-    pub fn transfer_token(ctx: Context<TransferToken>, params: TransferTokenParams) -> Result<()> {
+    pub fn claim_distribution(ctx: Context<ClaimDistribution>) -> Result<()> {
+        instructions::claim_distribution(ctx)
+    }
+
+    pub fn set_drip_election(
+        ctx: Context<SetDripElection>,
+        params: SetDripElectionParams,
+    ) -> Result<()> {
+        instructions::set_drip_election(ctx, &params)
+    }
+
+    pub fn reinvest_distributions(ctx: Context<ReinvestDistributions>) -> Result<()> {
+        instructions::reinvest_distributions(ctx)
+    }
+
+    pub fn transfer_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferToken<'info>>,
+        params: TransferTokenParams,
+    ) -> Result<()> {
         instructions::transfer_token(ctx, &params)
     }
+
+    // invoked by Token-2022 on every transfer of a hooked unit mint
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        instructions::transfer_hook(ctx, amount)
+    }
 }
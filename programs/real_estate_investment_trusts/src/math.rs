@@ -0,0 +1,90 @@
+//! Fixed-point amounts and pricing.
+//!
+//! Units of investment trusts are counted in whole units. Quote values (unit cost,
+//! funds raised, subscriptions, offering caps) are fixed-point numbers with
+//! `PRICE_SCALE` as one whole quote token, independent of the quote mint's decimals,
+//! e.g. a unit cost of 1.25 USDC is stored as 1_250_000.
+//!
+//! Both are converted to token base units only when tokens move, using the decimals
+//! of the mint involved. Intermediate products are computed in u128.
+
+use {crate::error::RealEstateInvestmentTrustsError, anchor_lang::prelude::*};
+
+// one whole quote token in fixed-point quote values
+pub const PRICE_SCALE: u64 = 1_000_000;
+
+// 100% in basis points
+pub const BASIS_POINTS: u16 = 10_000;
+
+// precision of the distribution accumulator, quote base units per unit
+pub const REWARD_SCALE: u128 = 1_000_000_000_000;
+
+// 10 ** decimals, failing instead of overflowing for large decimals
+pub fn pow10(decimals: u8) -> Result<u64> {
+    10u64
+        .checked_pow(decimals as u32)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+}
+
+// whole units to base units of the unit mint
+pub fn units_to_base_units(units: u64, decimals: u8) -> Result<u64> {
+    units
+        .checked_mul(pow10(decimals)?)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+}
+
+// fixed-point quote value of whole units at the unit cost
+pub fn quote_value(units: u64, unit_cost: u64) -> Result<u64> {
+    let value = (units as u128)
+        .checked_mul(unit_cost as u128)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    u64::try_from(value)
+        .map_err(|_| RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+}
+
+// fixed-point quote value to base units of the quote mint,
+// rejecting values the quote mint cannot represent exactly
+pub fn quote_to_base_units(value: u64, decimals: u8) -> Result<u64> {
+    let scaled = (value as u128)
+        .checked_mul(pow10(decimals)? as u128)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+    if scaled
+        .checked_rem(PRICE_SCALE as u128)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?
+        != 0
+    {
+        return Err(RealEstateInvestmentTrustsError::InvalidAmount.into());
+    }
+
+    u64::try_from(scaled / PRICE_SCALE as u128)
+        .map_err(|_| RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+}
+
+// base units of the quote mint to a fixed-point quote value, rounding down
+pub fn base_units_to_quote(amount: u64, decimals: u8) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(PRICE_SCALE as u128)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?
+        / pow10(decimals)? as u128;
+
+    u64::try_from(value)
+        .map_err(|_| RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+}
+
+// integer square root, rounding down
+pub fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x
+}
@@ -1,8 +1,23 @@
 // Program state handling.
 
+pub mod compliance_reason;
 pub mod configs;
 pub mod deposit_base;
+pub mod distribution;
+pub mod holding;
+pub mod income_epoch;
 pub mod investor;
+pub mod issuer_registry_entry;
+pub mod jurisdiction_policy;
+pub mod legacy;
 pub mod market_issuer;
+pub mod offering;
+pub mod order;
+pub mod pool;
 pub mod real_estate_investment_trust_scheme;
+pub mod redemption;
 pub mod reits_type;
+pub mod role;
+pub mod scheme_promoter;
+pub mod scheme_status;
+pub mod subscription;
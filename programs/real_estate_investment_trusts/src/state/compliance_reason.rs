@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// reason recorded with a compliance action on a holding
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, Debug, Default, InitSpace,
+)]
+pub enum ComplianceReason {
+    #[default]
+    None, // no compliance action on record
+    CourtOrder,      // ordered by a court
+    RegulatoryOrder, // ordered by a regulator
+    Sanctions,       // holder became subject to sanctions
+    Fraud,           // suspected fraud or theft of the units
+    LostKey,         // holder lost access to their wallet, units are recovered
+}
@@ -1,10 +1,49 @@
-use crate::state::market_issuer::MarketIssuer;
+use crate::state::role::{Role, RoleAssignment};
 use anchor_lang::prelude::*;
 
+// maximum number of role assignments held in the registry
+pub const MAX_ROLE_ASSIGNMENTS: usize = 10;
+
+// maximum number of sanctioned countries
+pub const MAX_SANCTIONED_COUNTRIES: usize = 16;
+
 #[account]
-#[derive(Default, InitSpace)]
+#[derive(InitSpace)]
 pub struct InvestmentTrustsConfigs {
-    #[max_len(5)]
-    pub issuers: Vec<MarketIssuer>,
+    pub admin: Pubkey,     // protocol admin authority
+    pub issuer_count: u64, // number of issuers in the registry
+    #[max_len(MAX_ROLE_ASSIGNMENTS)]
+    pub roles: Vec<RoleAssignment>, // role registry i.e compliance officers, approvers, pausers
+    pub paused: bool,      // trading halted by a pauser
     pub is_initialized: bool,
+    pub version: u8,                   // layout version, see migrate_account
+    pub max_nav_age: i64, // seconds a valuation stays usable for trading, 0 disables the check
+    pub min_payout_ratio_bps: u16, // share of reported income an income REIT must distribute
+    pub payout_grace_period: i64, // seconds after an income period to meet the payout ratio
+    pub redemption_window_length: i64, // seconds a redemption window stays open
+    pub redemption_gate_bps: u16, // share of net asset value redeemable per window, 0 disables the gate
+    pub sanctioned_countries: [[u8; 3]; MAX_SANCTIONED_COUNTRIES], // country codes no investor may trade from
+    pub sanctioned_count: u8, // number of codes in use in sanctioned_countries
+    pub pending_admin: Pubkey, // admin proposed by transfer_role, takes over on accept_admin_role
+    pub reserved: [u8; 19],   // room for new fields without a realloc
+}
+
+impl InvestmentTrustsConfigs {
+    pub const VERSION: u8 = 2;
+
+    // country codes as produced by jurisdiction_policy::country_code
+    pub fn is_sanctioned(&self, code: &[u8; 3]) -> bool {
+        self.sanctioned_countries[..self.sanctioned_count as usize].contains(code)
+    }
+
+    // the admin role is held by a single key, every other role lives in the registry
+    pub fn has_role(&self, authority: &Pubkey, role: Role) -> bool {
+        match role {
+            Role::Admin => self.admin == *authority,
+            _ => self
+                .roles
+                .iter()
+                .any(|assignment| assignment.role == role && assignment.authority == *authority),
+        }
+    }
 }
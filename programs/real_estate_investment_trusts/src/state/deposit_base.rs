@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
 
 #[account]
-#[derive(Default, Debug, InitSpace)]
+#[derive(Debug, InitSpace)]
 pub struct DepositBase {
     pub owner: Pubkey,
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the treasury belongs to
     pub admin_auth_bump: u8,
     pub admin_treasury_vault_bump: Option<u8>,
     pub is_initialized: bool,
+    pub version: u8,        // layout version, see migrate_account
+    pub reserved: [u8; 64], // room for new fields without a realloc
+}
+
+impl DepositBase {
+    pub const VERSION: u8 = 2;
 }
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// income distribution declared by a trust scheme, amounts in base units of the quote mint
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Distribution {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme paying the distribution
+    pub index: u64,                                  // index among the scheme's distributions
+    pub amount: u64,                                 // funds distributed to unit holders
+    pub record_date: i64, // unix timestamp holders are recorded at, i.e declaration
+    pub expires_at: i64,  // unix timestamp after which unclaimed funds can be swept
+    pub reward_per_unit: u128, // scheme accumulator including this distribution
+    pub swept: u64,       // unclaimed funds returned to the scheme
+    pub is_initialized: bool,
+}
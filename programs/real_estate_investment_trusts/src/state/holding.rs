@@ -0,0 +1,208 @@
+use crate::{
+    error::RealEstateInvestmentTrustsError,
+    math::REWARD_SCALE,
+    state::{
+        compliance_reason::ComplianceReason, investor::Investor,
+        real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+    },
+};
+use anchor_lang::prelude::*;
+
+// position of one investor in one trust scheme
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Holding {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the units belong to
+    pub investor: Pubkey,                            // investor account holding the units
+    pub owner: Pubkey,                               // publickey of the investor
+    pub units: u64,                                  // units of investment trusts held
+    pub cost_basis: u64,                             // funds paid for the units currently held
+    pub first_purchase_slot: u64,                    // slot of the first purchase
+    pub locked_until: i64, // unix timestamp before which units cannot be sold
+    pub is_initialized: bool,
+    pub version: u8,                         // layout version, see migrate_account
+    pub reward_checkpoint: u128,             // scheme accumulator at the last settlement
+    pub accrued_distributions: u64,          // distributions owed, in base units of the quote mint
+    pub accrued_through: u64,                // scheme distribution count at the last settlement
+    pub drip: bool,        // distributions are reinvested in units instead of paid out
+    pub queued_units: u64, // units queued for redemption, still held until filled
+    pub listed_units: u64, // units offered in open asks, still held until filled
+    pub frozen: bool,      // unit token account is frozen by a compliance officer
+    pub compliance_reason: ComplianceReason, // reason of the latest compliance action
+    pub accrued_from: u64, // oldest distribution in accrued_distributions, they expire with it
+    pub reserved: [u8; 69], // room for new fields without a realloc
+}
+
+impl Holding {
+    pub const VERSION: u8 = 2;
+
+    // set up the position on the investor's first settlement in the scheme
+    pub fn initialize(&mut self, scheme: Pubkey, investor: Pubkey, owner: Pubkey, slot: u64) {
+        if self.is_initialized {
+            return;
+        }
+
+        self.real_estate_investment_trust_scheme = scheme;
+        self.investor = investor;
+        self.owner = owner;
+        self.first_purchase_slot = slot;
+        self.is_initialized = true;
+        self.version = Self::VERSION;
+    }
+
+    // accrue distributions declared since the last settlement,
+    // runs before the units of the position change
+    pub fn settle_distributions(
+        &mut self,
+        real_estate_investment_trust_scheme: &RealEstateInvestmentTrustScheme,
+    ) -> Result<()> {
+        let reward_per_unit = real_estate_investment_trust_scheme.reward_per_unit;
+        let distribution_count = real_estate_investment_trust_scheme.distribution_count;
+        let swept_distributions = real_estate_investment_trust_scheme.swept_distributions;
+
+        // accrued funds expire with the oldest distribution they include,
+        // once it is swept they are no longer owed
+        if self.accrued_distributions > 0 && self.accrued_from < swept_distributions {
+            self.accrued_distributions = 0;
+        }
+
+        // the share of swept distributions is not owed either
+        let checkpoint = self
+            .reward_checkpoint
+            .max(real_estate_investment_trust_scheme.swept_reward_per_unit);
+        let pending = self.pending_distributions(checkpoint, reward_per_unit)?;
+
+        if self.accrued_distributions == 0 {
+            self.accrued_from = self.accrued_through.max(swept_distributions);
+        }
+        self.accrued_distributions = self
+            .accrued_distributions
+            .checked_add(pending)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        self.reward_checkpoint = reward_per_unit;
+        self.accrued_through = distribution_count;
+
+        Ok(())
+    }
+
+    // distributions earned by the units held from checkpoint up to reward_per_unit
+    fn pending_distributions(&self, checkpoint: u128, reward_per_unit: u128) -> Result<u64> {
+        let pending = reward_per_unit
+            .checked_sub(checkpoint)
+            .and_then(|delta| delta.checked_mul(self.units as u128))
+            .map(|value| value / REWARD_SCALE)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        u64::try_from(pending)
+            .map_err(|_| RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+    }
+
+    // units neither queued for redemption nor offered in an ask
+    pub fn free_units(&self) -> Result<u64> {
+        self.units
+            .checked_sub(self.queued_units)
+            .and_then(|units| units.checked_sub(self.listed_units))
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+    }
+
+    // add purchased units and their cost to the position
+    pub fn credit_units(&mut self, units: u64, cost: u64) -> Result<()> {
+        self.units = self
+            .units
+            .checked_add(units)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        self.cost_basis = self
+            .cost_basis
+            .checked_add(cost)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok(())
+    }
+
+    // remove units from the position, the cost basis shrinks pro rata (average cost),
+    // returns the cost released with the units
+    pub fn debit_units(&mut self, units: u64) -> Result<u64> {
+        if units > self.units {
+            return Err(RealEstateInvestmentTrustsError::InsufficientUnits.into());
+        }
+
+        let cost = (self.cost_basis as u128)
+            .checked_mul(units as u128)
+            .and_then(|value| value.checked_div(self.units as u128))
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        self.cost_basis = self
+            .cost_basis
+            .checked_sub(cost as u64)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        self.units -= units;
+
+        Ok(cost as u64)
+    }
+
+    // settle the position and add units bought, issued or received to it and
+    // its investor, a position going from zero to some units adds an investor
+    // to the scheme
+    pub fn credit(
+        &mut self,
+        real_estate_investment_trust_scheme: &mut RealEstateInvestmentTrustScheme,
+        investor: &mut Investor,
+        units: u64,
+        cost: u64,
+    ) -> Result<()> {
+        self.settle_distributions(real_estate_investment_trust_scheme)?;
+
+        let was_holder = self.units > 0;
+        self.credit_units(units, cost)?;
+        investor.add_units(units, cost)?;
+
+        if !was_holder && self.units > 0 {
+            real_estate_investment_trust_scheme.add_investor()?;
+        }
+
+        Ok(())
+    }
+
+    // settle the position and remove units sold, redeemed or sent from it and
+    // its investor, a position going down to zero no longer counts as an
+    // investor of the scheme, returns the cost released with the units
+    pub fn debit(
+        &mut self,
+        real_estate_investment_trust_scheme: &mut RealEstateInvestmentTrustScheme,
+        investor: &mut Investor,
+        units: u64,
+    ) -> Result<u64> {
+        self.settle_distributions(real_estate_investment_trust_scheme)?;
+
+        let was_holder = self.units > 0;
+        let cost = self.debit_units(units)?;
+        investor.remove_units(units, cost)?;
+
+        if was_holder && self.units == 0 {
+            real_estate_investment_trust_scheme.remove_investor()?;
+        }
+
+        Ok(cost)
+    }
+}
+
+// move units between two positions in a scheme, the cost basis moves with the
+// units, returns the cost moved
+pub fn move_units(
+    real_estate_investment_trust_scheme: &mut RealEstateInvestmentTrustScheme,
+    from_holding: &mut Holding,
+    from_investor: &mut Investor,
+    to_holding: &mut Holding,
+    to_investor: &mut Investor,
+    units: u64,
+) -> Result<u64> {
+    let cost = from_holding.debit(real_estate_investment_trust_scheme, from_investor, units)?;
+    to_holding.credit(
+        real_estate_investment_trust_scheme,
+        to_investor,
+        units,
+        cost,
+    )?;
+
+    Ok(cost)
+}
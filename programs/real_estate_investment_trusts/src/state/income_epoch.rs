@@ -0,0 +1,33 @@
+use crate::{error::RealEstateInvestmentTrustsError, math::BASIS_POINTS};
+use anchor_lang::prelude::*;
+
+// net distributable income a trust scheme reported for a period,
+// amounts in base units of the quote mint
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct IncomeEpoch {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme reporting the income
+    pub index: u64,                                  // index among the scheme's income epochs
+    pub period_start: i64,                           // unix timestamp the period starts
+    pub period_end: i64,                             // unix timestamp the period ends
+    pub net_distributable_income: u64,               // income reported for the period
+    pub min_payout_ratio_bps: u16,                   // share of the income that must be distributed
+    pub deadline: i64,    // unix timestamp the payout ratio must be met by
+    pub distributed: u64, // distributions declared against the period
+    pub flagged: bool,    // deadline missed, scheme is non-compliant until caught up
+    pub is_initialized: bool,
+}
+
+impl IncomeEpoch {
+    // distributions declared against the period cover the required share of its income
+    pub fn payout_met(&self) -> Result<bool> {
+        let distributed = (self.distributed as u128)
+            .checked_mul(BASIS_POINTS as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let required = (self.net_distributable_income as u128)
+            .checked_mul(self.min_payout_ratio_bps as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok(distributed >= required)
+    }
+}
@@ -1,7 +1,15 @@
+use crate::{
+    error::RealEstateInvestmentTrustsError,
+    state::{
+        configs::InvestmentTrustsConfigs,
+        jurisdiction_policy::{check_jurisdiction, JurisdictionPolicy},
+        real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+    },
+};
 use anchor_lang::prelude::*;
 
 #[account]
-#[derive(Default, Debug, InitSpace)]
+#[derive(Debug, InitSpace)]
 pub struct Investor {
     pub owner: Pubkey, // publickey of the investor
     #[max_len(50)]
@@ -9,6 +17,76 @@ pub struct Investor {
     #[max_len(3)]
     pub country: String, // home country of investor
     pub active: bool,  // status of investor
-    pub total_units_investment_trusts: u32, // total units of investment trusts owned by investor
-    pub available_funds: u32, // available funds equal to the investment trusts issued to investor
+    pub total_units_investment_trusts: u64, // total units of investment trusts owned by investor
+    pub available_funds: u64, // available funds equal to the investment trusts issued to investor, fixed-point quote value
+    pub version: u8,          // layout version, see migrate_account
+    pub kyc_level: u8,        // KYC tier attested by a compliance officer, 0 when not attested
+    pub kyc_expires_at: i64,  // unix timestamp the attestation expires
+    pub accredited: bool,     // accredited investor status
+    pub dossier_hash: [u8; 32], // hash of the off-chain KYC/AML dossier
+    pub attested_at: i64,     // unix timestamp of the latest attestation
+    pub reserved: [u8; 78],   // room for new fields without a realloc
+}
+
+impl Investor {
+    pub const VERSION: u8 = 2;
+
+    // trading requires an attestation that has not expired
+    pub fn check_attestation(&self, now: i64) -> Result<()> {
+        if self.kyc_level == 0 {
+            return Err(RealEstateInvestmentTrustsError::AttestationMissing.into());
+        }
+
+        if now >= self.kyc_expires_at {
+            return Err(RealEstateInvestmentTrustsError::AttestationExpired.into());
+        }
+
+        Ok(())
+    }
+
+    // an investor may take units of a scheme with a current attestation and a
+    // country that clears the sanctions list and the scheme's policy
+    pub fn check_eligibility(
+        &self,
+        investment_trusts_configs: &InvestmentTrustsConfigs,
+        real_estate_investment_trust_scheme: &RealEstateInvestmentTrustScheme,
+        jurisdiction_policy: Option<&JurisdictionPolicy>,
+        now: i64,
+    ) -> Result<()> {
+        self.check_attestation(now)?;
+        check_jurisdiction(
+            investment_trusts_configs,
+            real_estate_investment_trust_scheme,
+            jurisdiction_policy,
+            &self.country,
+        )
+    }
+
+    // book units added to the investor's positions with their cost
+    pub fn add_units(&mut self, units: u64, cost: u64) -> Result<()> {
+        self.total_units_investment_trusts = self
+            .total_units_investment_trusts
+            .checked_add(units)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        self.available_funds = self
+            .available_funds
+            .checked_add(cost)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok(())
+    }
+
+    // book units removed from the investor's positions with the cost they release
+    pub fn remove_units(&mut self, units: u64, cost: u64) -> Result<()> {
+        self.total_units_investment_trusts = self
+            .total_units_investment_trusts
+            .checked_sub(units)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        self.available_funds = self
+            .available_funds
+            .checked_sub(cost)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok(())
+    }
 }
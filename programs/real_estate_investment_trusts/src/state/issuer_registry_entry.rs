@@ -0,0 +1,12 @@
+use crate::state::market_issuer::MarketIssuer;
+use anchor_lang::prelude::*;
+
+// one entry per registered trust scheme, replaces the bounded issuers list on the configs
+#[account]
+#[derive(InitSpace)]
+pub struct IssuerRegistryEntry {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the issuer listed
+    pub issuer: MarketIssuer,
+    pub registered_at: i64, // unix timestamp of registration
+    pub is_initialized: bool,
+}
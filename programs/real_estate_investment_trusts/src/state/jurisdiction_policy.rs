@@ -0,0 +1,77 @@
+use crate::{
+    error::RealEstateInvestmentTrustsError,
+    state::{
+        configs::InvestmentTrustsConfigs,
+        real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+    },
+};
+use anchor_lang::prelude::*;
+
+// maximum number of country codes on a scheme's list
+pub const MAX_JURISDICTIONS: usize = 32;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum JurisdictionMode {
+    AllowList, // only investors from the listed countries
+    DenyList,  // investors from any country except the listed ones
+}
+
+// investor countries a trust scheme accepts
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct JurisdictionPolicy {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the policy applies to
+    pub mode: JurisdictionMode,
+    #[max_len(MAX_JURISDICTIONS)]
+    pub countries: Vec<[u8; 3]>, // country codes, see country_code
+    pub is_initialized: bool,
+}
+
+// upper case country code, two letter codes padded with a zero byte
+pub fn country_code(country: &str) -> Result<[u8; 3]> {
+    let bytes = country.as_bytes();
+    if bytes.len() != 2 && bytes.len() != 3 {
+        return Err(RealEstateInvestmentTrustsError::InvalidCountryLength.into());
+    }
+
+    let mut code = [0u8; 3];
+    for (i, byte) in bytes.iter().enumerate() {
+        code[i] = byte.to_ascii_uppercase();
+    }
+
+    Ok(code)
+}
+
+// an investor country passes the global sanctions list and then the scheme's policy,
+// a scheme that has a policy must be given it
+pub fn check_jurisdiction(
+    investment_trusts_configs: &InvestmentTrustsConfigs,
+    real_estate_investment_trust_scheme: &RealEstateInvestmentTrustScheme,
+    jurisdiction_policy: Option<&JurisdictionPolicy>,
+    country: &str,
+) -> Result<()> {
+    let code = country_code(country)?;
+
+    if investment_trusts_configs.is_sanctioned(&code) {
+        return Err(RealEstateInvestmentTrustsError::CountrySanctioned.into());
+    }
+
+    let policy = match jurisdiction_policy {
+        Some(policy) => policy,
+        None if real_estate_investment_trust_scheme.has_jurisdiction_policy => {
+            return Err(RealEstateInvestmentTrustsError::JurisdictionPolicyMissing.into())
+        }
+        None => return Ok(()),
+    };
+
+    let listed = policy.countries.contains(&code);
+    match policy.mode {
+        JurisdictionMode::AllowList if !listed => {
+            Err(RealEstateInvestmentTrustsError::CountryNotAllowed.into())
+        }
+        JurisdictionMode::DenyList if listed => {
+            Err(RealEstateInvestmentTrustsError::CountryDenied.into())
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,110 @@
+use crate::state::{
+    configs::MAX_ROLE_ASSIGNMENTS, market_issuer::MarketIssuer, offering::OfferingStatus,
+    role::RoleAssignment, scheme_status::SchemeStatus,
+};
+use anchor_lang::prelude::*;
+
+// earlier account layouts, kept so that migrate_account can read accounts created under them
+//
+// v0 - amounts in u32 whole tokens, the original investor layout
+// v1 - amounts in u64 with fixed-point quote values, no version byte or reserved padding
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct InvestorV0 {
+    pub owner: Pubkey,
+    #[max_len(50)]
+    pub full_names: String,
+    #[max_len(3)]
+    pub country: String,
+    pub active: bool,
+    pub total_units_investment_trusts: u32, // whole units
+    pub available_funds: u32,               // whole quote tokens
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct InvestorV1 {
+    pub owner: Pubkey,
+    #[max_len(50)]
+    pub full_names: String,
+    #[max_len(3)]
+    pub country: String,
+    pub active: bool,
+    pub total_units_investment_trusts: u64,
+    pub available_funds: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct OfferingV1 {
+    pub status: OfferingStatus,
+    pub soft_cap: u64,
+    pub hard_cap: u64,
+    pub min_ticket: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub raised: u64,
+    pub escrow: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct RealEstateInvestmentTrustSchemeV1 {
+    pub owner: Pubkey,
+    pub scheme_id: u64,
+    pub bump: u8,
+    pub issuer: MarketIssuer,
+    #[max_len(3)]
+    pub country: String,
+    pub status: SchemeStatus,
+    pub investor_funds_raised: u64,
+    pub is_initialized: bool,
+    pub investor_count: u64,
+    pub lock_up_period: i64,
+    pub unit_cost_of_investment_trusts: u64,
+    pub decimals: u8,
+    pub quote_mint: Pubkey,
+    pub settlement_vault: Pubkey,
+    pub unit_mint: Pubkey,
+    pub total_units_investment_trusts: u64,
+    pub deposit_account: Pubkey,
+    pub offering: OfferingV1,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct SubscriptionV0 {
+    pub real_estate_investment_trust_scheme: Pubkey,
+    pub investor: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u32, // whole quote tokens
+    pub claimed: bool,
+    pub is_initialized: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct InvestmentTrustsConfigsV1 {
+    pub admin: Pubkey,
+    pub issuer_count: u64,
+    #[max_len(MAX_ROLE_ASSIGNMENTS)]
+    pub roles: Vec<RoleAssignment>,
+    pub paused: bool,
+    pub is_initialized: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct DepositBaseV1 {
+    pub owner: Pubkey,
+    pub real_estate_investment_trust_scheme: Pubkey,
+    pub admin_auth_bump: u8,
+    pub admin_treasury_vault_bump: Option<u8>,
+    pub is_initialized: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct HoldingV1 {
+    pub real_estate_investment_trust_scheme: Pubkey,
+    pub investor: Pubkey,
+    pub owner: Pubkey,
+    pub units: u64,
+    pub cost_basis: u64,
+    pub first_purchase_slot: u64,
+    pub locked_until: i64,
+    pub is_initialized: bool,
+}
@@ -1,6 +1,14 @@
 //use crate::state::reits_type::ReitsType;
+use crate::error::RealEstateInvestmentTrustsError;
 use anchor_lang::prelude::*;
 
+// issuer length
+const ISSUER_LENGTH: usize = 30;
+// name length
+const NAME_LENGTH: usize = 30;
+// listing date length
+const LISTING_DATE_LENGTH: usize = 20;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct MarketIssuer {
     #[max_len(30)]
@@ -11,3 +19,44 @@ pub struct MarketIssuer {
     #[max_len(20)]
     pub listing_date: String,
 }
+
+impl MarketIssuer {
+    pub fn validate(&self) -> Result<()> {
+        if self.issuer.is_empty() || self.issuer.len() > ISSUER_LENGTH {
+            return Err(RealEstateInvestmentTrustsError::InvalidIssuerLength.into());
+        }
+
+        if self.name.is_empty() || self.name.len() > NAME_LENGTH {
+            return Err(RealEstateInvestmentTrustsError::InvalidNameLength.into());
+        }
+
+        // 1 - DevelopmentRealEstateInvestmentTrusts i.e (D-REITs)
+        // 2 - IncomeRealEstateInvestmentTrust i.e (I-REITs)
+        if !matches!(self.type_of_reit, 1 | 2) {
+            return Err(RealEstateInvestmentTrustsError::InvalidTypeOfReit.into());
+        }
+
+        if self.listing_date.is_empty() || self.listing_date.len() > LISTING_DATE_LENGTH {
+            return Err(RealEstateInvestmentTrustsError::InvalidListingDateLength.into());
+        }
+
+        Ok(())
+    }
+
+    // token symbol of the unit mint
+    pub fn symbol(&self) -> &'static str {
+        match self.type_of_reit {
+            1 => "D-REIT",
+            _ => "I-REIT",
+        }
+    }
+
+    // details shown alongside the name and symbol in the unit mint's token metadata
+    pub fn additional_metadata(&self) -> Vec<(String, String)> {
+        vec![
+            ("issuer".to_string(), self.issuer.clone()),
+            ("type_of_reit".to_string(), self.symbol().to_string()),
+            ("listing_date".to_string(), self.listing_date.clone()),
+        ]
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, Debug, Default, InitSpace,
+)]
+pub enum OfferingStatus {
+    #[default]
+    None, // no primary offering configured
+    Open,      // accepting subscriptions within the offering window
+    Succeeded, // soft cap met, subscribers claim their units
+    Failed,    // soft cap missed, subscribers claim refunds
+}
+
+// primary subscription offering (IPO) of a trust scheme,
+// caps, tickets and funds raised are fixed-point quote values
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct Offering {
+    pub status: OfferingStatus,
+    pub soft_cap: u64,   // minimum raise for the offering to succeed
+    pub hard_cap: u64,   // maximum raise accepted
+    pub min_ticket: u64, // minimum subscription per investor
+    pub start_time: i64, // unix timestamp the offering opens
+    pub end_time: i64,   // unix timestamp the offering closes
+    pub raised: u64,     // funds subscribed so far
+    pub escrow: Pubkey,  // token account holding subscriptions until finalized
+    pub price: u64,      // unit cost when the offering was configured, NAV updates do not change it
+}
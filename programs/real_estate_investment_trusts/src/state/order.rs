@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+// ask posted by a holder on the scheme's secondary market, units held in escrow
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Order {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the units belong to
+    pub index: u64,                                  // index among the scheme's orders
+    pub investor: Pubkey,                            // investor account selling the units
+    pub owner: Pubkey,                               // publickey of the seller
+    pub units: u64,                                  // whole units still offered
+    pub price: u64,                                  // price per unit, fixed-point quote value
+    pub escrow: Pubkey,                              // token account holding the offered units
+    pub created_at: i64,                             // unix timestamp the ask was posted
+    pub is_initialized: bool,
+}
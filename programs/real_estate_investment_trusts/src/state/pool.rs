@@ -0,0 +1,133 @@
+use crate::{error::RealEstateInvestmentTrustsError, math::BASIS_POINTS};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SwapDirection {
+    BuyUnits,  // quote tokens in, units out
+    SellUnits, // units in, quote tokens out
+}
+
+// constant-product pool of a scheme's units against its quote mint,
+// units are whole units and quote amounts are base units of the quote mint
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Pool {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the units belong to
+    pub unit_vault: Pubkey,                          // token account holding the pooled units
+    pub quote_vault: Pubkey, // token account holding the pooled quote tokens
+    pub unit_reserve: u64,   // whole units in the pool
+    pub quote_reserve: u64,  // quote tokens in the pool, fees included
+    pub total_shares: u64,   // shares issued to liquidity providers
+    pub fee_bps: u16,        // swap fee kept in the pool for liquidity providers
+    pub price_band_bps: u16, // largest deviation of the pool price from NAV
+    pub fees_accrued: u64,   // swap fees collected to date
+    pub is_initialized: bool,
+}
+
+impl Pool {
+    // the pool price per unit stays within the band around the NAV price per unit,
+    // both in base units of the quote mint
+    pub fn check_price_band(&self, nav_price: u64) -> Result<()> {
+        let pool_value = self.quote_reserve as u128;
+        let nav_value = (nav_price as u128)
+            .checked_mul(self.unit_reserve as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        let deviation = pool_value
+            .abs_diff(nav_value)
+            .checked_mul(BASIS_POINTS as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let band = nav_value
+            .checked_mul(self.price_band_bps as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        if deviation > band {
+            return Err(RealEstateInvestmentTrustsError::PoolPriceOutOfBand.into());
+        }
+
+        Ok(())
+    }
+
+    // quote tokens paid for units out of the pool, returns (amount in, fee)
+    pub fn buy_units(&mut self, units: u64) -> Result<(u64, u64)> {
+        if units >= self.unit_reserve {
+            return Err(RealEstateInvestmentTrustsError::PoolEmpty.into());
+        }
+
+        // x * y = k, rounding in favour of the pool
+        let remaining = (self.unit_reserve - units) as u128;
+        let amount = (self.quote_reserve as u128)
+            .checked_mul(units as u128)
+            .and_then(|value| value.checked_add(remaining - 1))
+            .map(|value| value / remaining)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let fee_divisor = (BASIS_POINTS - self.fee_bps) as u128;
+        let amount_in = amount
+            .checked_mul(BASIS_POINTS as u128)
+            .and_then(|value| value.checked_add(fee_divisor - 1))
+            .map(|value| value / fee_divisor)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        let amount_in = u64::try_from(amount_in)
+            .map_err(|_| RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let fee = amount_in - amount as u64;
+
+        self.unit_reserve -= units;
+        self.quote_reserve = self
+            .quote_reserve
+            .checked_add(amount_in)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        self.fees_accrued = self
+            .fees_accrued
+            .checked_add(fee)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok((amount_in, fee))
+    }
+
+    // quote tokens received for units into the pool, returns (amount out, fee)
+    pub fn sell_units(&mut self, units: u64) -> Result<(u64, u64)> {
+        if self.unit_reserve == 0 {
+            return Err(RealEstateInvestmentTrustsError::PoolEmpty.into());
+        }
+
+        // x * y = k, rounding in favour of the pool
+        let unit_reserve = self
+            .unit_reserve
+            .checked_add(units)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let amount = (self.quote_reserve as u128)
+            .checked_mul(units as u128)
+            .map(|value| value / unit_reserve as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        let fee = amount
+            .checked_mul(self.fee_bps as u128)
+            .and_then(|value| value.checked_add(BASIS_POINTS as u128 - 1))
+            .map(|value| value / BASIS_POINTS as u128)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        // amount is below the quote reserve, so both fit in u64
+        let amount_out = (amount - fee) as u64;
+        let fee = fee as u64;
+
+        self.unit_reserve = unit_reserve;
+        self.quote_reserve -= amount_out;
+        self.fees_accrued = self
+            .fees_accrued
+            .checked_add(fee)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok((amount_out, fee))
+    }
+}
+
+// liquidity provider whitelisted by the scheme promoter
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct LiquidityProvider {
+    pub pool: Pubkey,      // pool the shares belong to
+    pub investor: Pubkey,  // investor account providing liquidity
+    pub owner: Pubkey,     // publickey of the investor
+    pub shares: u64,       // pool shares held
+    pub whitelisted: bool, // may add liquidity, shares can always be withdrawn
+    pub is_initialized: bool,
+}
@@ -1,18 +1,117 @@
-use crate::state::market_issuer::MarketIssuer;
+use crate::error::RealEstateInvestmentTrustsError;
+use crate::state::{market_issuer::MarketIssuer, offering::Offering, scheme_status::SchemeStatus};
 use anchor_lang::prelude::*;
 
 #[account]
 #[derive(InitSpace)]
 pub struct RealEstateInvestmentTrustScheme {
-    pub owner: Pubkey, // publickey of the trust scheme promoter
+    pub owner: Pubkey,  // publickey of the trust scheme promoter
+    pub scheme_id: u64, // index of the scheme among the promoter's schemes
+    pub bump: u8,       // bump of the scheme address
     pub issuer: MarketIssuer,
     #[max_len(3)]
     pub country: String, // home country where trust scheme is implemented
-    pub active: bool,               // status of trust scheme
-    pub investor_funds_raised: u32, // funds raised by investors
+    pub status: SchemeStatus,       // lifecycle status of trust scheme
+    pub investor_funds_raised: u64, // funds raised by investors, fixed-point quote value
     pub is_initialized: bool,       // is trust scheme initiated
-    #[max_len(5)]
-    pub investors: Vec<Pubkey>, // list of the investors
-    pub unit_cost_of_investment_trusts: u32, // unit cost of investment trusts
-    pub decimals: u8,               // decimals for the token mint
+    pub investor_count: u64,        // number of investors currently holding units
+    pub lock_up_period: i64,        // seconds after a purchase before units can be sold
+    pub unit_cost_of_investment_trusts: u64, // unit cost of investment trusts, fixed-point quote value
+    pub decimals: u8,                        // decimals for the token mint
+    pub quote_mint: Pubkey,                  // mint investors pay with i.e USDC
+    pub settlement_vault: Pubkey,            // treasury token account holding the quote mint
+    pub unit_mint: Pubkey,                   // mint of the investment trust units
+    pub total_units_investment_trusts: u64,  // units issued, matches the unit mint supply
+    pub deposit_account: Pubkey,             // deposit base that owns the treasury vault
+    pub offering: Offering,                  // primary subscription offering
+    pub version: u8,                         // layout version, see migrate_account
+    pub net_asset_value: u64, // net asset value of the scheme, fixed-point quote value
+    pub valuation_timestamp: i64, // unix timestamp of the latest valuation
+    pub appraisal_hash: [u8; 32], // hash of the appraisal document behind the valuation
+    pub reward_per_unit: u128, // distributions per unit to date, quote base units scaled by REWARD_SCALE
+    pub distribution_count: u64, // number of distributions declared
+    pub distribution_vault: Pubkey, // token account holding declared distributions
+    pub last_distribution_expiry: i64, // expiry of the latest distribution, expiries never decrease
+    pub income_epoch_count: u64, // number of income periods reported
+    pub non_compliant_epochs: u64, // income periods past their deadline without the required payout
+    pub redemption_window: u64, // index of the open redemption window
+    pub redemption_window_start: i64, // unix timestamp the open redemption window started, 0 before the first request
+    pub queued_redemption_units: u64, // units queued in the open window, including carried over requests
+    pub redemption_escrow: Pubkey,    // token account holding queued units
+    pub order_count: u64,             // number of asks posted on the secondary market
+    pub has_jurisdiction_policy: bool, // investor countries are restricted by a jurisdiction policy
+    pub unit_transfer_hook: bool, // unit mint is a Token-2022 mint with this program as its transfer hook
+    pub unit_metadata: bool,      // unit mint carries token metadata from the issuer details
+    pub unit_permanent_delegate: bool, // scheme is the permanent delegate of the unit mint, units can be force transferred
+    pub pooled_units: u64,             // units held in the liquidity pool, not owned by any holding
+    pub swept_reward_per_unit: u128,   // scheme accumulator up to the last swept distribution
+    pub swept_distributions: u64,      // number of distributions swept, they are swept in order
+    pub unswept_distribution_amount: u64, // funds declared in distributions not swept yet
+    pub returning_units: bool, // escrowed units are being returned to their holder, read by the transfer hook
+    pub reserved: [u8; 267],   // room for new fields without a realloc
+}
+
+impl RealEstateInvestmentTrustScheme {
+    pub const VERSION: u8 = 2;
+
+    // units trade at the latest valuation only while it is within the configured age
+    pub fn check_valuation_fresh(&self, max_nav_age: i64, now: i64) -> Result<()> {
+        if max_nav_age == 0 {
+            return Ok(());
+        }
+
+        let age = now
+            .checked_sub(self.valuation_timestamp)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+        if age > max_nav_age {
+            return Err(RealEstateInvestmentTrustsError::ValuationStale.into());
+        }
+
+        Ok(())
+    }
+
+    // the scheme address signs for the unit mint, its vaults and escrows
+    pub fn signer(&self) -> SchemeSigner {
+        SchemeSigner {
+            owner: self.owner,
+            scheme_id: self.scheme_id.to_le_bytes(),
+            bump: [self.bump],
+        }
+    }
+
+    pub fn add_investor(&mut self) -> Result<()> {
+        self.investor_count = self
+            .investor_count
+            .checked_add(1)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok(())
+    }
+
+    pub fn remove_investor(&mut self) -> Result<()> {
+        self.investor_count = self
+            .investor_count
+            .checked_sub(1)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?;
+
+        Ok(())
+    }
+}
+
+// owned seeds of a scheme address, see RealEstateInvestmentTrustScheme::signer
+pub struct SchemeSigner {
+    owner: Pubkey,
+    scheme_id: [u8; 8],
+    bump: [u8; 1],
+}
+
+impl SchemeSigner {
+    pub fn seeds(&self) -> [&[u8]; 4] {
+        [
+            b"investment-trust-scheme",
+            self.owner.as_ref(),
+            &self.scheme_id,
+            &self.bump,
+        ]
+    }
 }
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+// units an investor queued for redemption, carried from window to window until filled
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RedemptionRequest {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the units are redeemed from
+    pub investor: Pubkey,                            // investor account redeeming the units
+    pub owner: Pubkey,                               // publickey of the investor
+    pub units: u64,                                  // whole units still waiting to be filled
+    pub window: u64,                                 // redemption window the units are queued in
+    pub is_initialized: bool,
+}
+
+// outcome of a closed redemption window, requests settle against it pro rata
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RedemptionWindow {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme the window belongs to
+    pub index: u64,                                  // index among the scheme's windows
+    pub units_queued: u64,                           // units queued when the window closed
+    pub units_filled: u64,                           // units redeemed within the gate
+    pub units_settled: u64,                          // queued units of the requests settled so far
+    pub units_paid: u64, // units redeemed by the requests settled so far
+    pub unit_cost: u64,  // redemption price, fixed-point quote value
+    pub closed_at: i64,  // unix timestamp the window closed
+    pub is_initialized: bool,
+}
+
+impl RedemptionWindow {
+    // share of the queued units of a request filled in this window, the pro rata
+    // share is rounded down on the running total of settled units so the fills
+    // of all requests add up to units_filled and the rest carries over exactly
+    pub fn fill(&mut self, units: u64) -> Option<u64> {
+        let units_settled = self.units_settled.checked_add(units)?;
+        let units_paid = if self.units_filled >= self.units_queued {
+            units_settled
+        } else {
+            let paid = (units_settled as u128)
+                .checked_mul(self.units_filled as u128)?
+                .checked_div(self.units_queued as u128)?;
+            u64::try_from(paid).ok()?
+        };
+
+        let fill = units_paid.checked_sub(self.units_paid)?;
+        self.units_settled = units_settled;
+        self.units_paid = units_paid;
+
+        Some(fill)
+    }
+}
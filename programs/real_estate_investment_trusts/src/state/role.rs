@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum Role {
+    Admin,             // protocol admin, manages the role registry
+    ComplianceOfficer, // attests and polices investors
+    SchemeApprover,    // approves trust schemes for listing
+    Pauser,            // halts trading in emergencies
+    Valuer,            // posts net asset valuations of trust schemes
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct RoleAssignment {
+    pub role: Role,
+    pub authority: Pubkey, // publickey holding the role
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct SchemePromoter {
+    pub owner: Pubkey,     // publickey of the trust scheme promoter
+    pub scheme_count: u64, // number of schemes registered, also the next scheme id
+    pub is_initialized: bool,
+}
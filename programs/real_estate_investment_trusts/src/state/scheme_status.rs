@@ -0,0 +1,47 @@
+use crate::error::RealEstateInvestmentTrustsError;
+use anchor_lang::prelude::*;
+
+// lifecycle of a trust scheme from registration to delisting
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, Debug, Default, InitSpace,
+)]
+pub enum SchemeStatus {
+    #[default]
+    Pending, // registered by the promoter, awaiting review
+    UnderReview, // picked up by a scheme approver
+    Approved,    // approved, not yet open for trading
+    Listed,      // open for buying and selling
+    Suspended,   // trading halted, can be listed again
+    Delisted,    // rejected or permanently removed
+}
+
+impl SchemeStatus {
+    // allowed status changes, keyed by the target status and listing the
+    // statuses a scheme may move to it from
+    pub fn transition(from: SchemeStatus, to: SchemeStatus) -> Result<SchemeStatus> {
+        let allowed = match to {
+            // picked up by a scheme approver
+            SchemeStatus::UnderReview => matches!(from, SchemeStatus::Pending),
+            // approved for listing
+            SchemeStatus::Approved => {
+                matches!(from, SchemeStatus::Pending | SchemeStatus::UnderReview)
+            }
+            // opened for trading, or reinstated after a suspension
+            SchemeStatus::Listed => {
+                matches!(from, SchemeStatus::Approved | SchemeStatus::Suspended)
+            }
+            // temporarily closed for trading
+            SchemeStatus::Suspended => matches!(from, SchemeStatus::Listed),
+            // rejected before listing or permanently removed from the market
+            SchemeStatus::Delisted => !matches!(from, SchemeStatus::Delisted),
+            // a scheme never returns to pending
+            SchemeStatus::Pending => false,
+        };
+
+        if !allowed {
+            return Err(RealEstateInvestmentTrustsError::InvalidSchemeStatusTransition.into());
+        }
+
+        Ok(to)
+    }
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+// funds one investor committed to a scheme's primary offering
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct Subscription {
+    pub real_estate_investment_trust_scheme: Pubkey, // trust scheme being subscribed to
+    pub investor: Pubkey,                            // investor account subscribing
+    pub owner: Pubkey,                               // publickey of the investor
+    pub amount: u64,   // funds committed to the offering, fixed-point quote value
+    pub claimed: bool, // units issued or refund paid after finalization
+    pub is_initialized: bool,
+}
@@ -0,0 +1,159 @@
+//! Token program helpers shared by the legacy and Token-2022 mints
+
+use {
+    crate::{
+        error::RealEstateInvestmentTrustsError,
+        state::real_estate_investment_trust_scheme::RealEstateInvestmentTrustScheme,
+    },
+    anchor_lang::{prelude::*, system_program},
+    anchor_spl::{
+        token_2022::spl_token_2022::{
+            self,
+            extension::{
+                transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+            },
+            onchain::invoke_transfer_checked,
+        },
+        token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_interface::{
+            token_metadata_update_field, Mint, TokenMetadataUpdateField, TransferChecked,
+        },
+    },
+};
+
+// amount the sender must move for the recipient to receive amount in full,
+// i.e. amount plus the mint's transfer fee for the current epoch
+pub fn with_transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+
+    // legacy mints and Token-2022 mints without the extension charge nothing
+    let fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?,
+        Err(_) => 0,
+    };
+
+    amount
+        .checked_add(fee)
+        .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation.into())
+}
+
+// transfer_checked that resolves the mint's transfer hook accounts from the
+// remaining accounts, a mint without a hook transfers as usual
+pub fn transfer_checked_with_hook<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+// returns escrowed units (cancelled asks, pool withdrawals) to the holder who
+// placed them, the flag is written to the scheme account before the transfer
+// so the hook lets the units back even if the holder is no longer eligible
+pub fn return_units_with_hook<'info>(
+    real_estate_investment_trust_scheme: &mut Account<'info, RealEstateInvestmentTrustScheme>,
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    real_estate_investment_trust_scheme.returning_units = true;
+    real_estate_investment_trust_scheme.exit(&crate::ID)?;
+
+    transfer_checked_with_hook(ctx, amount, decimals)?;
+
+    real_estate_investment_trust_scheme.returning_units = false;
+
+    Ok(())
+}
+
+// units of a mint with the transfer hook only move with the scheme as
+// authority, signing as permanent delegate, so the hook can refuse transfers
+// made outside the program, units of a legacy mint are moved by the holder
+pub fn unit_transfer_authority<'info>(
+    real_estate_investment_trust_scheme: &Account<'info, RealEstateInvestmentTrustScheme>,
+    owner: &AccountInfo<'info>,
+) -> AccountInfo<'info> {
+    if real_estate_investment_trust_scheme.unit_transfer_hook {
+        real_estate_investment_trust_scheme.to_account_info()
+    } else {
+        owner.clone()
+    }
+}
+
+// writes fields into a mint's token metadata, every field reallocs the mint so
+// its rent is topped up from payer for the final size first
+pub fn update_token_metadata_fields<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    update_authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fields: Vec<(Field, String)>,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    let space = {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let mut token_metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        let current_size = token_metadata.tlv_size_of()?;
+
+        for (field, value) in fields.iter() {
+            token_metadata.update(field.clone(), value.clone());
+        }
+
+        data.len()
+            .checked_sub(current_size)
+            .and_then(|len| len.checked_add(token_metadata.tlv_size_of().ok()?))
+            .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?
+    };
+
+    let minimum_balance = Rent::get()?.minimum_balance(space);
+    let lamports = mint.lamports();
+    if minimum_balance > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            minimum_balance
+                .checked_sub(lamports)
+                .ok_or(RealEstateInvestmentTrustsError::InvalidArithmeticOperation)?,
+        )?;
+    }
+
+    for (field, value) in fields {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    token_program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: update_authority.clone(),
+                },
+                signer,
+            ),
+            field,
+            value,
+        )?;
+    }
+
+    Ok(())
+}
@@ -3,10 +3,13 @@ import { Program } from "@coral-xyz/anchor";
 import { RealEstateInvestmentTrusts } from "../target/types/real_estate_investment_trusts";
 import {
   Account,
-  createAccount,
+  createMint,
   getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
 import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { assert } from "chai";
 
 describe("real_estate_investment_trusts", () => {
   // Configure the client to use the local cluster.
@@ -16,8 +19,11 @@ describe("real_estate_investment_trusts", () => {
   const program = anchor.workspace
     .RealEstateInvestmentTrusts as Program<RealEstateInvestmentTrusts>;
   const adminOwner = anchor.web3.Keypair.generate();
+  const complianceOfficer = anchor.web3.Keypair.generate();
+  const schemeApprover = anchor.web3.Keypair.generate();
+  const pauser = anchor.web3.Keypair.generate();
+  const valuer = anchor.web3.Keypair.generate();
   const trustSchemePromoter = anchor.web3.Keypair.generate();
-  const depositAccount = anchor.web3.Keypair.generate();
   /* const usdcMint = new anchor.web3.PublicKey(
     "4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"
   ); // USDC devnet */
@@ -26,22 +32,52 @@ describe("real_estate_investment_trusts", () => {
   const associateTokenProgram = new anchor.web3.PublicKey(
     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
   );
-  const mintToken = anchor.web3.Keypair.generate(); // dummy usdc token created for test purposes
-  const tokenAccount = anchor.utils.token.associatedAddress({
-    mint: mintToken.publicKey,
-    owner: payer.publicKey,
-  });
-
   let investorOwner = anchor.web3.Keypair.generate();
-  let investorOwnerATA = anchor.web3.Keypair.generate();
 
-  let treasuryVaultATA: Account;
+  let usdcMint: anchor.web3.PublicKey; // dummy usdc token created for test purposes
+  let investorOwnerATA: Account; // investor's usdc token account
+  let investorUnitsATA: Account; // investor's unit token account
+  let promoterUnitsATA: Account; // promoter's unit token account
+  let settlementVault: anchor.web3.PublicKey;
+
+  let [investmentTrustsConfigs] = anchor.web3.PublicKey.findProgramAddressSync(
+    [anchor.utils.bytes.utf8.encode("investment-trusts-configs")],
+    program.programId
+  );
+
+  let [schemePromoter] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("scheme-promoter"),
+      trustSchemePromoter.publicKey.toBuffer(),
+    ],
+    program.programId
+  );
+
+  // first scheme of the promoter i.e scheme id 0
+  const schemeId = new anchor.BN(0);
+  let [realEstateInvestmentTrustScheme] =
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("investment-trust-scheme"),
+        trustSchemePromoter.publicKey.toBuffer(),
+        schemeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+  let [depositAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("deposit-base"),
+      realEstateInvestmentTrustScheme.toBuffer(),
+    ],
+    program.programId
+  );
 
   // pdaAuth
   let [pdaAuth, adminPdaBump] = anchor.web3.PublicKey.findProgramAddressSync(
     [
       anchor.utils.bytes.utf8.encode("auth"),
-      depositAccount.publicKey.toBuffer(),
+      depositAccount.toBuffer(),
     ],
     program.programId
   );
@@ -51,19 +87,22 @@ describe("real_estate_investment_trusts", () => {
       program.programId
     );
 
-  let [investmentTrustsConfigs] = anchor.web3.PublicKey.findProgramAddressSync(
-    [anchor.utils.bytes.utf8.encode("investment-trusts-configs")],
+  // unit mint of the trust scheme
+  let [mintToken] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("unit-mint"),
+      realEstateInvestmentTrustScheme.toBuffer(),
+    ],
     program.programId
   );
 
-  let [realEstateInvestmentTrustScheme] =
-    anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        anchor.utils.bytes.utf8.encode("investment-trust-scheme"),
-        trustSchemePromoter.publicKey.toBuffer(),
-      ],
-      program.programId
-    );
+  let [issuerRegistryEntry] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("issuer-registry"),
+      realEstateInvestmentTrustScheme.toBuffer(),
+    ],
+    program.programId
+  );
 
   let [investor] = anchor.web3.PublicKey.findProgramAddressSync(
     [
@@ -72,6 +111,22 @@ describe("real_estate_investment_trusts", () => {
     ],
     program.programId
   );
+  let [holding] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("holding"),
+      realEstateInvestmentTrustScheme.toBuffer(),
+      investor.toBuffer(),
+    ],
+    program.programId
+  );
+  let [jurisdictionPolicy] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("jurisdiction-policy"),
+      realEstateInvestmentTrustScheme.toBuffer(),
+    ],
+    program.programId
+  );
+
 
   // admin owner
   before(async () => {
@@ -121,14 +176,101 @@ describe("real_estate_investment_trusts", () => {
     });
   });
 
+  // dummy usdc mint and investor funds
+  before(async () => {
+    usdcMint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      9 // token mint in smallest unit i.e 9 decimals
+    );
+
+    investorOwnerATA = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      usdcMint,
+      investorOwner.publicKey
+    );
+
+    await mintTo(
+      provider.connection,
+      payer,
+      usdcMint,
+      investorOwnerATA.address,
+      payer,
+      1_000_000_000_000_000 // 1,000,000 usdc
+    );
+
+    settlementVault = anchor.utils.token.associatedAddress({
+      mint: usdcMint,
+      owner: treasuryVault,
+    });
+  });
+
   it("Is initialized!", async () => {
     try {
       let initParams = {
         isInitialized: true,
       };
 
+      // only the upgrade authority (the provider wallet) may initialize
+      const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+
       const tx = await program.methods
         .init(initParams)
+        .accounts({
+          owner: payer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          program: program.programId,
+          programData: programData,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.investmentTrustsConfigs.fetch(
+        investmentTrustsConfigs
+      );
+      console.log("investmentTrustsConfigs: ", result);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is transfer admin role!", async () => {
+    try {
+      let transferRoleParams = {
+        role: { admin: {} },
+        authority: payer.publicKey,
+        newAuthority: adminOwner.publicKey,
+      };
+
+      const tx = await program.methods
+        .transferRole(transferRoleParams)
+        .accounts({
+          owner: payer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const tx = await program.methods
+        .acceptAdminRole()
         .accounts({
           owner: adminOwner.publicKey,
           investmentTrustsConfigs: investmentTrustsConfigs,
@@ -141,6 +283,48 @@ describe("real_estate_investment_trusts", () => {
       console.log(error);
     }
 
+    try {
+      let result = await program.account.investmentTrustsConfigs.fetch(
+        investmentTrustsConfigs
+      );
+      assert.equal(result.admin.toBase58(), adminOwner.publicKey.toBase58());
+      assert.equal(
+        result.pendingAdmin.toBase58(),
+        anchor.web3.PublicKey.default.toBase58()
+      );
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is grant roles!", async () => {
+    let roles = [
+      {
+        role: { complianceOfficer: {} },
+        authority: complianceOfficer.publicKey,
+      },
+      { role: { schemeApprover: {} }, authority: schemeApprover.publicKey },
+      { role: { pauser: {} }, authority: pauser.publicKey },
+      { role: { valuer: {} }, authority: valuer.publicKey },
+    ];
+
+    for (let initParams of roles) {
+      try {
+        const tx = await program.methods
+          .grantRole(initParams)
+          .accounts({
+            owner: adminOwner.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([adminOwner])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+    }
+
     try {
       let result = await program.account.investmentTrustsConfigs.fetch(
         investmentTrustsConfigs
@@ -151,6 +335,29 @@ describe("real_estate_investment_trusts", () => {
     }
   });
 
+  it("Is pause and unpause!", async () => {
+    for (let paused of [true, false]) {
+      try {
+        let initParams = {
+          paused: paused,
+        };
+
+        const tx = await program.methods
+          .setPaused(initParams)
+          .accounts({
+            owner: pauser.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([pauser])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+    }
+  });
+
   it("Is register investment trust scheme!", async () => {
     // typeOfReit
     // 1 - DevelopmentRealEstateInvestmentTrusts i.e (D-REITs)
@@ -165,10 +372,13 @@ describe("real_estate_investment_trusts", () => {
       };
 
       let initParams = {
+        schemeId: schemeId,
         issuer: marketIssuer,
         country: "KE",
-        unitCostOfInvestmentTrusts: 1, // unit cost of investment trusts
+        // unit cost of investment trusts, fixed-point with 1_000_000 as one quote token
+        unitCostOfInvestmentTrusts: new anchor.BN(1_000_000),
         decimals: 9, // token mint in smallest unit i.e 9 decimals
+        lockUpPeriod: new anchor.BN(0), // seconds before purchased units can be sold
       };
 
       const tx = await program.methods
@@ -176,13 +386,19 @@ describe("real_estate_investment_trusts", () => {
         .accounts({
           owner: trustSchemePromoter.publicKey,
           investmentTrustsConfigs: investmentTrustsConfigs,
+          schemePromoter: schemePromoter,
           realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
-          depositAccount: depositAccount.publicKey,
+          issuerRegistryEntry: issuerRegistryEntry,
+          depositAccount: depositAccount,
           pdaAuth: pdaAuth,
           treasuryVault: treasuryVault,
+          quoteMint: usdcMint,
+          settlementVault: settlementVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: associateTokenProgram,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([trustSchemePromoter, depositAccount])
+        .signers([trustSchemePromoter])
         .rpc();
       console.log("Your transaction signature", tx);
     } catch (error) {
@@ -193,9 +409,7 @@ describe("real_estate_investment_trusts", () => {
       let result = await program.account.realEstateInvestmentTrustScheme.fetch(
         realEstateInvestmentTrustScheme
       );
-      let result2 = await program.account.depositBase.fetch(
-        depositAccount.publicKey
-      );
+      let result2 = await program.account.depositBase.fetch(depositAccount);
       let result3 = await program.account.investmentTrustsConfigs.fetch(
         investmentTrustsConfigs
       );
@@ -207,27 +421,76 @@ describe("real_estate_investment_trusts", () => {
     }
   });
 
-  it("Is create token!", async () => {
-    console.log("mint token: ", mintToken.publicKey.toBase58());
-    console.log("token account: ", tokenAccount.toBase58());
+  it("Is migrate account!", async () => {
+    // v1 accounts loaded into the test validator from tests/fixtures
+    const investorV1 = new anchor.web3.PublicKey(
+      "969FZAgbcBSMQuun8qCHjx9tENV9X2UbEDR5TRQzCsVC"
+    );
+    const depositBaseV1 = new anchor.web3.PublicKey(
+      "7gjxccDJzLK2rwRcsw7fTniQPvdZk8CKRx2ET1SmEJq9"
+    );
+
+    for (let account of [investorV1, depositBaseV1]) {
+      try {
+        const tx = await program.methods
+          .migrateAccount()
+          .accounts({
+            owner: adminOwner.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            account: account,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([adminOwner])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+    }
 
     try {
-      let initParams = {
-        amount: new anchor.BN(100),
-      };
+      let result = await program.account.investor.fetch(investorV1);
+      console.log("investor: ", result);
+      assert.equal(result.version, 2);
+      assert.equal(
+        result.owner.toBase58(),
+        "De1EfdQjtAUn8e6Pgs2LjtPtPE6PxYELfok4xgYTXs6z"
+      );
+      assert.equal(result.fullNames, "jane wanjiku");
+      assert.equal(result.country, "KE");
+      assert.isTrue(result.active);
+      assert.equal(result.totalUnitsInvestmentTrusts.toNumber(), 25);
+      assert.equal(result.availableFunds.toNumber(), 25_000_000);
+
+      let result2 = await program.account.depositBase.fetch(depositBaseV1);
+      console.log("deposit account: ", result2);
+      assert.equal(result2.version, 2);
+      assert.equal(
+        result2.owner.toBase58(),
+        "8F5aF2AZr29tLrR1NoxZYPt8KZkepaTixvLmQS99Wj1m"
+      );
+      assert.equal(
+        result2.realEstateInvestmentTrustScheme.toBase58(),
+        "3wXvL9djMjn7P5C4EjM2PoNpPEnTjHLpS2EtoYzowvoB"
+      );
+      assert.equal(result2.adminAuthBump, 254);
+      assert.equal(result2.adminTreasuryVaultBump, 253);
+      assert.isTrue(result2.isInitialized);
+    } catch (error) {
+      console.log(error);
+    }
 
+    // accounts created with the current layout are rejected as already migrated
+    try {
       const tx = await program.methods
-        .createToken(initParams)
+        .migrateAccount()
         .accounts({
-          owner: payer.publicKey,
-          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
-          mintToken: mintToken.publicKey,
-          tokenAccount: tokenAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associateTokenProgram: associateTokenProgram,
+          owner: adminOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          account: realEstateInvestmentTrustScheme,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([mintToken])
+        .signers([adminOwner])
         .rpc();
       console.log("Your transaction signature", tx);
     } catch (error) {
@@ -235,64 +498,133 @@ describe("real_estate_investment_trusts", () => {
     }
   });
 
-  it("Is token transfer", async () => {
-    console.log(
-      "investor owner token account: ",
-      investorOwnerATA.publicKey.toBase58()
-    );
+  it("Is review, approve and list investment trust scheme!", async () => {
+    for (let status of [
+      { underReview: {} },
+      { approved: {} },
+      { listed: {} },
+    ]) {
+      try {
+        const tx = await program.methods
+          .setSchemeStatus({ status: status })
+          .accounts({
+            owner: schemeApprover.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([schemeApprover])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+    }
 
     try {
-      await createAccount(
-        provider.connection,
-        investorOwner,
-        mintToken.publicKey,
-        investorOwner.publicKey,
-        investorOwnerATA
+      let result = await program.account.realEstateInvestmentTrustScheme.fetch(
+        realEstateInvestmentTrustScheme
       );
+      console.log("real estate investment trust scheme: ", result);
     } catch (error) {
       console.log(error);
     }
+  });
+
+  it("Is register, reject and deregister second investment trust scheme!", async () => {
+    // second scheme of the same promoter i.e scheme id 1
+    const secondSchemeId = new anchor.BN(1);
+    let [secondScheme] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("investment-trust-scheme"),
+        trustSchemePromoter.publicKey.toBuffer(),
+        secondSchemeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [secondIssuerRegistryEntry] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("issuer-registry"),
+          secondScheme.toBuffer(),
+        ],
+        program.programId
+      );
+    let [secondDepositAccount] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("deposit-base"),
+          secondScheme.toBuffer(),
+        ],
+        program.programId
+      );
+    let [secondPdaAuth] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("auth"),
+        secondDepositAccount.toBuffer(),
+      ],
+      program.programId
+    );
+    let [secondTreasuryVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("treasury-vault"),
+        secondPdaAuth.toBuffer(),
+      ],
+      program.programId
+    );
 
     try {
       let initParams = {
-        amount: new anchor.BN(70),
+        schemeId: secondSchemeId,
+        issuer: {
+          issuer: "Acorn Holdings Limited",
+          name: "Acorn D-REIT",
+          typeOfReit: 1, // DevelopmentRealEstateInvestmentTrusts
+          listingDate: "April 2024",
+        },
+        country: "KE",
+        unitCostOfInvestmentTrusts: new anchor.BN(1_000_000),
+        decimals: 9,
+        lockUpPeriod: new anchor.BN(86400), // one day lock-up
       };
+
       const tx = await program.methods
-        .transferToken(initParams)
+        .registerInvestmentTrustScheme(initParams)
         .accounts({
-          owner: payer.publicKey,
-          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
-          mintToken: mintToken.publicKey,
-          fromAccount: tokenAccount,
-          toAccount: investorOwnerATA.publicKey,
+          owner: trustSchemePromoter.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          schemePromoter: schemePromoter,
+          realEstateInvestmentTrustScheme: secondScheme,
+          issuerRegistryEntry: secondIssuerRegistryEntry,
+          depositAccount: secondDepositAccount,
+          pdaAuth: secondPdaAuth,
+          treasuryVault: secondTreasuryVault,
+          quoteMint: usdcMint,
+          settlementVault: anchor.utils.token.associatedAddress({
+            mint: usdcMint,
+            owner: secondTreasuryVault,
+          }),
           tokenProgram: TOKEN_PROGRAM_ID,
-          associateTokenProgram: associateTokenProgram,
+          associatedTokenProgram: associateTokenProgram,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([mintToken])
+        .signers([trustSchemePromoter])
         .rpc();
-
       console.log("Your transaction signature", tx);
     } catch (error) {
       console.log(error);
     }
-  });
 
-  it("Is register first investor!", async () => {
     try {
-      let initParams = {
-        fullNames: "paul john",
-        country: "KE",
-      };
-
       const tx = await program.methods
-        .registerInvestor(initParams)
+        .setSchemeStatus({ status: { delisted: {} } }) // rejected before listing
         .accounts({
-          owner: investorOwner.publicKey,
-          investor: investor,
+          owner: schemeApprover.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: secondScheme,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([investorOwner])
+        .signers([schemeApprover])
         .rpc();
       console.log("Your transaction signature", tx);
     } catch (error) {
@@ -300,49 +632,47 @@ describe("real_estate_investment_trusts", () => {
     }
 
     try {
-      let result = await program.account.investor.fetch(investor);
-      console.log("investor: ", result);
+      const tx = await program.methods
+        .deregisterIssuer()
+        .accounts({
+          owner: schemeApprover.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: secondScheme,
+          issuerRegistryEntry: secondIssuerRegistryEntry,
+          promoter: trustSchemePromoter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([schemeApprover])
+        .rpc();
+      console.log("Your transaction signature", tx);
     } catch (error) {
       console.log(error);
     }
-  });
 
-  it("Is buy investment trusts!", async () => {
     try {
-      treasuryVaultATA = await getOrCreateAssociatedTokenAccount(
-        provider.connection,
-        payer,
-        mintToken.publicKey,
-        treasuryVault,
-        true
-      );
-      console.log(
-        "treasuryVaultATA address: " + treasuryVaultATA.address.toBase58()
+      let result = await program.account.investmentTrustsConfigs.fetch(
+        investmentTrustsConfigs
       );
+      console.log("investment trusts configs: ", result);
     } catch (error) {
       console.log(error);
     }
+  });
 
-    try {
-      let initParams = {
-        // 10 amount of token to transfer (in smallest unit i.e 9 decimals)
-        amount: new anchor.BN(10),
-      };
+  it("Is create token!", async () => {
+    console.log("mint token: ", mintToken.toBase58());
 
+    try {
       const tx = await program.methods
-        .buyInvestmentTrusts(initParams)
+        .createToken()
         .accounts({
-          owner: investorOwner.publicKey,
+          owner: trustSchemePromoter.publicKey,
           realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
-          investor: investor,
-          senderTokens: investorOwnerATA.publicKey,
-          recipientTokens: treasuryVaultATA.address,
-          mintToken: mintToken.publicKey,
+          mintToken: mintToken,
           tokenProgram: TOKEN_PROGRAM_ID,
-          associateTokenProgram: associateTokenProgram,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([investorOwner])
+        .signers([trustSchemePromoter])
         .rpc();
       console.log("Your transaction signature", tx);
     } catch (error) {
@@ -350,38 +680,39 @@ describe("real_estate_investment_trusts", () => {
     }
 
     try {
-      let result = await program.account.investor.fetch(investor);
-      console.log("investor: ", result);
-
-      let result2 = await program.account.realEstateInvestmentTrustScheme.fetch(
-        realEstateInvestmentTrustScheme
+      investorUnitsATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        mintToken,
+        investorOwner.publicKey
+      );
+      promoterUnitsATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        mintToken,
+        trustSchemePromoter.publicKey
+      );
+      console.log(
+        "investor owner token account: ",
+        investorUnitsATA.address.toBase58()
       );
-      console.log("real estate investment trust scheme: ", result2);
     } catch (error) {
       console.log(error);
     }
   });
 
-  it("Is sell investment trusts!", async () => {
+  it("Is register first investor!", async () => {
     try {
       let initParams = {
-        // 3 amount of token to transfer (in smallest unit i.e 9 decimals)
-        amount: new anchor.BN(3),
+        fullNames: "paul john",
+        country: "KE",
       };
+
       const tx = await program.methods
-        .sellInvestmentTrusts(initParams)
+        .registerInvestor(initParams)
         .accounts({
           owner: investorOwner.publicKey,
-          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
           investor: investor,
-          senderTokens: treasuryVaultATA.address,
-          recipientTokens: investorOwnerATA.publicKey,
-          mintToken: mintToken.publicKey,
-          depositAccount: depositAccount.publicKey,
-          pdaAuth: pdaAuth,
-          treasuryVault: treasuryVault,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associateTokenProgram: associateTokenProgram,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([investorOwner])
@@ -391,19 +722,2325 @@ describe("real_estate_investment_trusts", () => {
       console.log(error);
     }
 
+    // the investor stays inactive until a compliance officer attests them
     try {
-      let result = await program.account.depositBase.fetch(
-        depositAccount.publicKey
-      );
-      console.log("deposit account: ", result);
-
-      let result2 = await program.account.investor.fetch(investor);
-      console.log("investor: ", result2);
+      let initParams = {
+        kycLevel: 2,
+        expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 31536000), // a year
+        accredited: true,
+        dossierHash: Array(32).fill(2), // hash of the off-chain KYC/AML dossier
+      };
 
-      let result3 = await program.account.realEstateInvestmentTrustScheme.fetch(
-        realEstateInvestmentTrustScheme
+      const tx = await program.methods
+        .attestInvestor(initParams)
+        .accounts({
+          owner: complianceOfficer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          investor: investor,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.investor.fetch(investor);
+      console.log("investor: ", result);
+      assert.isTrue(result.active);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is update configs and net asset value!", async () => {
+    try {
+      let initParams = {
+        maxNavAge: new anchor.BN(86400), // valuations older than a day halt trading
+        minPayoutRatioBps: 8000, // income REITs pay out at least 80% of income
+        payoutGracePeriod: new anchor.BN(86400), // a day after the period ends
+        redemptionWindowLength: new anchor.BN(0), // windows can close at any time
+        redemptionGateBps: 500, // at most 5% of net asset value redeemed per window
+      };
+
+      const tx = await program.methods
+        .updateConfigs(initParams)
+        .accounts({
+          owner: adminOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([adminOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        netAssetValue: new anchor.BN(500_000_000_000), // 500,000 usdc
+        navPerUnit: new anchor.BN(1_000_000), // 1 usdc per unit
+        appraisalHash: Array(32).fill(1), // hash of the appraisal report
+      };
+
+      const tx = await program.methods
+        .updateNav(initParams)
+        .accounts({
+          owner: valuer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          quoteMint: usdcMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([valuer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.realEstateInvestmentTrustScheme.fetch(
+        realEstateInvestmentTrustScheme
+      );
+      console.log("real estate investment trust scheme: ", result);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is jurisdiction policy!", async () => {
+    try {
+      let initParams = {
+        countries: ["KP", "IR"], // no scheme may take investors from these countries
+      };
+
+      const tx = await program.methods
+        .setSanctionedCountries(initParams)
+        .accounts({
+          owner: adminOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([adminOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        mode: { denyList: {} },
+        countries: ["US"], // the scheme is not offered to US investors
+      };
+
+      const tx = await program.methods
+        .setJurisdictionPolicy(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.jurisdictionPolicy.fetch(
+        jurisdictionPolicy
+      );
+      console.log("jurisdiction policy: ", result);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is buy investment trusts!", async () => {
+    try {
+      let initParams = {
+        // 10 units at the scheme's unit cost, paid in the quote mint
+        amount: new anchor.BN(10),
+      };
+
+      const tx = await program.methods
+        .buyInvestmentTrusts(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          investor: investor,
+          holding: holding,
+          senderTokens: investorOwnerATA.address,
+          recipientTokens: settlementVault,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.investor.fetch(investor);
+      console.log("investor: ", result);
+
+      let result2 = await program.account.realEstateInvestmentTrustScheme.fetch(
+        realEstateInvestmentTrustScheme
+      );
+      console.log("real estate investment trust scheme: ", result2);
+
+      let result3 = await program.account.holding.fetch(holding);
+      console.log("holding: ", result3);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is token transfer", async () => {
+    // the promoter registers as an investor to receive units
+    let [promoterInvestor] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("investor"),
+        trustSchemePromoter.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    try {
+      let initParams = {
+        fullNames: "john promoter",
+        country: "KE",
+      };
+
+      const tx = await program.methods
+        .registerInvestor(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          investor: promoterInvestor,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        kycLevel: 1,
+        expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 31536000), // a year
+        accredited: false,
+        dossierHash: Array(32).fill(3),
+      };
+
+      const tx = await program.methods
+        .attestInvestor(initParams)
+        .accounts({
+          owner: complianceOfficer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          investor: promoterInvestor,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    let [promoterHolding] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("holding"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        promoterInvestor.toBuffer(),
+      ],
+      program.programId
+    );
+
+    try {
+      let initParams = {
+        amount: new anchor.BN(2),
+      };
+      const tx = await program.methods
+        .transferToken(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          mintToken: mintToken,
+          fromAccount: investorUnitsATA.address,
+          toAccount: promoterUnitsATA.address,
+          investor: investor,
+          holding: holding,
+          recipientInvestor: promoterInvestor,
+          recipientHolding: promoterHolding,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is sell investment trusts!", async () => {
+    let [redemptionRequest] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        investor.toBuffer(),
+      ],
+      program.programId
+    );
+    let [redemptionEscrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption-escrow"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    // first redemption window of the scheme i.e index 0
+    const redemptionWindowIndex = new anchor.BN(0);
+    let [redemptionWindow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption-window"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        redemptionWindowIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    // the units are queued in the open redemption window
+    try {
+      let initParams = {
+        // 3 units redeemed at the scheme's unit cost
+        amount: new anchor.BN(3),
+      };
+      const tx = await program.methods
+        .sellInvestmentTrusts(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          redemptionRequest: redemptionRequest,
+          redemptionEscrow: redemptionEscrow,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // any signer may close the window once it has run its length
+    try {
+      const tx = await program.methods
+        .closeRedemptionWindow()
+        .accounts({
+          owner: payer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          redemptionWindow: redemptionWindow,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the request is paid its share of the gate from the treasury
+    try {
+      const tx = await program.methods
+        .settleRedemption()
+        .accounts({
+          owner: payer.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          redemptionWindow: redemptionWindow,
+          redemptionRequest: redemptionRequest,
+          investor: investor,
+          holding: holding,
+          redemptionEscrow: redemptionEscrow,
+          unitMint: mintToken,
+          senderTokens: settlementVault,
+          recipientTokens: investorOwnerATA.address,
+          mintToken: usdcMint,
+          depositAccount: depositAccount,
+          pdaAuth: pdaAuth,
+          treasuryVault: treasuryVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.redemptionWindow.fetch(
+        redemptionWindow
+      );
+      console.log("redemption window: ", result);
+
+      let result2 = await program.account.redemptionRequest.fetch(
+        redemptionRequest
+      );
+      console.log("redemption request: ", result2);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.depositBase.fetch(depositAccount);
+      console.log("deposit account: ", result);
+
+      let result2 = await program.account.investor.fetch(investor);
+      console.log("investor: ", result2);
+
+      let result3 = await program.account.realEstateInvestmentTrustScheme.fetch(
+        realEstateInvestmentTrustScheme
+      );
+      console.log("real estate investment trust scheme: ", result3);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is redemption gate split across requests!", async () => {
+    // three investors queue 1 unit each against a gate of 2 units
+    const redeemers = [0, 1, 2].map(() => anchor.web3.Keypair.generate());
+    let [redemptionEscrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption-escrow"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    // second redemption window of the scheme i.e index 1
+    const redemptionWindowIndex = new anchor.BN(1);
+    let [redemptionWindow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption-window"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        redemptionWindowIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    let accounts = [];
+    for (let [i, redeemerOwner] of redeemers.entries()) {
+      let [redeemer] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("investor"),
+          redeemerOwner.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      let [redeemerHolding] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("holding"),
+          realEstateInvestmentTrustScheme.toBuffer(),
+          redeemer.toBuffer(),
+        ],
+        program.programId
+      );
+      let [redemptionRequest] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("redemption"),
+          realEstateInvestmentTrustScheme.toBuffer(),
+          redeemer.toBuffer(),
+        ],
+        program.programId
+      );
+
+      let redeemerOwnerATA: Account; // redeemer's usdc token account
+      let redeemerUnitsATA: Account; // redeemer's unit token account
+      try {
+        let res = await provider.connection.requestAirdrop(
+          redeemerOwner.publicKey,
+          10 * anchor.web3.LAMPORTS_PER_SOL
+        );
+
+        let latestBlockHash = await provider.connection.getLatestBlockhash();
+
+        await provider.connection.confirmTransaction({
+          blockhash: latestBlockHash.blockhash,
+          lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+          signature: res,
+        });
+
+        redeemerOwnerATA = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer,
+          usdcMint,
+          redeemerOwner.publicKey
+        );
+
+        await mintTo(
+          provider.connection,
+          payer,
+          usdcMint,
+          redeemerOwnerATA.address,
+          payer,
+          100_000_000_000 // 100 usdc
+        );
+
+        redeemerUnitsATA = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          payer,
+          mintToken,
+          redeemerOwner.publicKey
+        );
+      } catch (error) {
+        console.log(error);
+      }
+
+      try {
+        let initParams = {
+          fullNames: `redeemer ${i}`,
+          country: "KE",
+        };
+
+        const tx = await program.methods
+          .registerInvestor(initParams)
+          .accounts({
+            owner: redeemerOwner.publicKey,
+            investor: redeemer,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([redeemerOwner])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+
+      try {
+        let initParams = {
+          kycLevel: 1,
+          expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 31536000), // a year
+          accredited: false,
+          dossierHash: Array(32).fill(5 + i),
+        };
+
+        const tx = await program.methods
+          .attestInvestor(initParams)
+          .accounts({
+            owner: complianceOfficer.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            investor: redeemer,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([complianceOfficer])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+
+      try {
+        const tx = await program.methods
+          .buyInvestmentTrusts({ amount: new anchor.BN(1) })
+          .accounts({
+            owner: redeemerOwner.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+            jurisdictionPolicy: jurisdictionPolicy,
+            investor: redeemer,
+            holding: redeemerHolding,
+            senderTokens: redeemerOwnerATA.address,
+            recipientTokens: settlementVault,
+            mintToken: usdcMint,
+            unitMint: mintToken,
+            investorUnits: redeemerUnitsATA.address,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
+            associateTokenProgram: associateTokenProgram,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([redeemerOwner])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+
+      try {
+        const tx = await program.methods
+          .sellInvestmentTrusts({ amount: new anchor.BN(1) })
+          .accounts({
+            owner: redeemerOwner.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+            investor: redeemer,
+            holding: redeemerHolding,
+            redemptionRequest: redemptionRequest,
+            redemptionEscrow: redemptionEscrow,
+            unitMint: mintToken,
+            investorUnits: redeemerUnitsATA.address,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associateTokenProgram: associateTokenProgram,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([redeemerOwner])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+
+      accounts.push({
+        redeemer,
+        redeemerHolding,
+        redemptionRequest,
+        redeemerOwnerATA,
+      });
+    }
+
+    // a net asset value of 2 usdc with the gate at 100% lets 2 units through
+    try {
+      const tx = await program.methods
+        .updateConfigs({
+          maxNavAge: new anchor.BN(86400),
+          minPayoutRatioBps: 8000,
+          payoutGracePeriod: new anchor.BN(86400),
+          redemptionWindowLength: new anchor.BN(0),
+          redemptionGateBps: 10000,
+        })
+        .accounts({
+          owner: adminOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([adminOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+
+      const tx2 = await program.methods
+        .updateNav({
+          netAssetValue: new anchor.BN(2_000_000), // 2 usdc
+          navPerUnit: new anchor.BN(1_000_000), // 1 usdc per unit
+          appraisalHash: Array(32).fill(1),
+        })
+        .accounts({
+          owner: valuer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          quoteMint: usdcMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([valuer])
+        .rpc();
+      console.log("Your transaction signature", tx2);
+
+      const tx3 = await program.methods
+        .closeRedemptionWindow()
+        .accounts({
+          owner: payer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          redemptionWindow: redemptionWindow,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx3);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // each request settles its share, the floored remainders add up to the gate
+    for (let account of accounts) {
+      try {
+        const tx = await program.methods
+          .settleRedemption()
+          .accounts({
+            owner: payer.publicKey,
+            realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+            redemptionWindow: redemptionWindow,
+            redemptionRequest: account.redemptionRequest,
+            investor: account.redeemer,
+            holding: account.redeemerHolding,
+            redemptionEscrow: redemptionEscrow,
+            unitMint: mintToken,
+            senderTokens: settlementVault,
+            recipientTokens: account.redeemerOwnerATA.address,
+            mintToken: usdcMint,
+            depositAccount: depositAccount,
+            pdaAuth: pdaAuth,
+            treasuryVault: treasuryVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+    }
+
+    try {
+      let result = await program.account.redemptionWindow.fetch(
+        redemptionWindow
+      );
+      console.log("redemption window: ", result);
+      assert.equal(result.unitsFilled.toNumber(), 2);
+      assert.equal(result.unitsPaid.toNumber(), 2);
+
+      // the unit left unfilled matches the units carried to the next window
+      let carried = 0;
+      for (let account of accounts) {
+        let request = await program.account.redemptionRequest.fetch(
+          account.redemptionRequest
+        );
+        carried += request.units.toNumber();
+      }
+      let result2 = await program.account.realEstateInvestmentTrustScheme.fetch(
+        realEstateInvestmentTrustScheme
+      );
+      assert.equal(carried, 1);
+      assert.equal(result2.queuedRedemptionUnits.toNumber(), carried);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // back to the earlier valuation and gate
+    try {
+      const tx = await program.methods
+        .updateConfigs({
+          maxNavAge: new anchor.BN(86400),
+          minPayoutRatioBps: 8000,
+          payoutGracePeriod: new anchor.BN(86400),
+          redemptionWindowLength: new anchor.BN(0),
+          redemptionGateBps: 500,
+        })
+        .accounts({
+          owner: adminOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([adminOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+
+      const tx2 = await program.methods
+        .updateNav({
+          netAssetValue: new anchor.BN(500_000_000_000),
+          navPerUnit: new anchor.BN(1_000_000),
+          appraisalHash: Array(32).fill(1),
+        })
+        .accounts({
+          owner: valuer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          quoteMint: usdcMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([valuer])
+        .rpc();
+      console.log("Your transaction signature", tx2);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is suspend and relist investment trust scheme!", async () => {
+    for (let status of [
+      { suspended: {} },
+      { listed: {} },
+    ]) {
+      try {
+        const tx = await program.methods
+          .setSchemeStatus({ status: status })
+          .accounts({
+            owner: schemeApprover.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([schemeApprover])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+    }
+  });
+
+  it("Is declare and claim distribution!", async () => {
+    // first income period of the scheme i.e index 0
+    const incomeEpochIndex = new anchor.BN(0);
+    let [incomeEpoch] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("income-epoch"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        incomeEpochIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    // first distribution of the scheme i.e index 0
+    const distributionIndex = new anchor.BN(0);
+    let [distribution] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("distribution"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        distributionIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [distributionVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("distribution-vault"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+
+    let promoterOwnerATA: Account; // promoter's usdc token account
+    try {
+      promoterOwnerATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        usdcMint,
+        trustSchemePromoter.publicKey
+      );
+
+      await mintTo(
+        provider.connection,
+        payer,
+        usdcMint,
+        promoterOwnerATA.address,
+        payer,
+        1_000_000_000_000 // 1,000 usdc of rental income
+      );
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const now = Math.floor(Date.now() / 1000);
+      let initParams = {
+        netDistributableIncome: new anchor.BN(8_000_000), // 8 usdc, fixed-point quote value
+        periodStart: new anchor.BN(now - 86400 * 30),
+        periodEnd: new anchor.BN(now),
+      };
+
+      const tx = await program.methods
+        .postIncomeEpoch(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          incomeEpoch: incomeEpoch,
+          mintToken: usdcMint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        amount: new anchor.BN(7_000_000), // 7 usdc, fixed-point quote value
+        claimPeriod: new anchor.BN(3600), // unclaimed funds can be swept after an hour
+      };
+
+      const tx = await program.methods
+        .declareDistribution(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          distribution: distribution,
+          incomeEpoch: incomeEpoch,
+          distributionVault: distributionVault,
+          senderTokens: promoterOwnerATA.address,
+          mintToken: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const tx = await program.methods
+        .claimDistribution()
+        .accounts({
+          owner: investorOwner.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          distribution: distribution, // the holding accrued from this distribution
+          distributionVault: distributionVault,
+          recipientTokens: investorOwnerATA.address,
+          mintToken: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the distribution has not expired yet, so sweeping is rejected
+    try {
+      const tx = await program.methods
+        .sweepDistribution()
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          distribution: distribution,
+          distributionVault: distributionVault,
+          settlementVault: settlementVault,
+          mintToken: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the payout deadline has not passed yet, so the scheme cannot be flagged
+    try {
+      const tx = await program.methods
+        .checkPayoutCompliance()
+        .accounts({
+          owner: payer.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          incomeEpoch: incomeEpoch,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.distribution.fetch(distribution);
+      console.log("distribution: ", result);
+
+      let result3 = await program.account.incomeEpoch.fetch(incomeEpoch);
+      console.log("income epoch: ", result3);
+
+      let result2 = await program.account.holding.fetch(holding);
+      console.log("holding: ", result2);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is reinvest distributions!", async () => {
+    // second distribution of the scheme i.e index 1, paid against the same period
+    const incomeEpochIndex = new anchor.BN(0);
+    let [incomeEpoch] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("income-epoch"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        incomeEpochIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const distributionIndex = new anchor.BN(1);
+    let [distribution] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("distribution"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        distributionIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [distributionVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("distribution-vault"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // the investor elects to reinvest distributions in units
+    try {
+      let initParams = {
+        drip: true,
+      };
+
+      const tx = await program.methods
+        .setDripElection(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const promoterOwnerATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        usdcMint,
+        trustSchemePromoter.publicKey
+      );
+
+      let initParams = {
+        amount: new anchor.BN(3_000_000), // 3 usdc, fixed-point quote value
+        claimPeriod: new anchor.BN(3600), // unclaimed funds can be swept after an hour
+      };
+
+      const tx = await program.methods
+        .declareDistribution(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          distribution: distribution,
+          incomeEpoch: incomeEpoch,
+          distributionVault: distributionVault,
+          senderTokens: promoterOwnerATA.address,
+          mintToken: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // any signer may reinvest on behalf of the holder
+    try {
+      const tx = await program.methods
+        .reinvestDistributions()
+        .accounts({
+          owner: payer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          investor: investor,
+          holding: holding,
+          distribution: distribution,
+          distributionVault: distributionVault,
+          settlementVault: settlementVault,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.holding.fetch(holding);
+      console.log("holding: ", result);
+      assert.isTrue(result.drip);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is post, fill and cancel ask!", async () => {
+    // second investor buying units from the first on the secondary market
+    const buyerOwner = anchor.web3.Keypair.generate();
+    let [buyer] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("investor"),
+        buyerOwner.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    let [buyerHolding] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("holding"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        buyer.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // first ask posted on the scheme i.e index 0
+    const orderIndex = new anchor.BN(0);
+    let [order] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("order"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        orderIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [orderEscrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("order-escrow"), order.toBuffer()],
+      program.programId
+    );
+
+    let buyerOwnerATA: Account; // buyer's usdc token account
+    let buyerUnitsATA: Account; // buyer's unit token account
+    try {
+      let res = await provider.connection.requestAirdrop(
+        buyerOwner.publicKey,
+        10 * anchor.web3.LAMPORTS_PER_SOL
+      );
+
+      let latestBlockHash = await provider.connection.getLatestBlockhash();
+
+      await provider.connection.confirmTransaction({
+        blockhash: latestBlockHash.blockhash,
+        lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+        signature: res,
+      });
+
+      buyerOwnerATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        usdcMint,
+        buyerOwner.publicKey
+      );
+
+      await mintTo(
+        provider.connection,
+        payer,
+        usdcMint,
+        buyerOwnerATA.address,
+        payer,
+        100_000_000_000 // 100 usdc
+      );
+
+      buyerUnitsATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        mintToken,
+        buyerOwner.publicKey
+      );
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        fullNames: "mary jane",
+        country: "KE",
+      };
+
+      const tx = await program.methods
+        .registerInvestor(initParams)
+        .accounts({
+          owner: buyerOwner.publicKey,
+          investor: buyer,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([buyerOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        kycLevel: 1,
+        expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 31536000), // a year
+        accredited: false,
+        dossierHash: Array(32).fill(4),
+      };
+
+      const tx = await program.methods
+        .attestInvestor(initParams)
+        .accounts({
+          owner: complianceOfficer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          investor: buyer,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the first investor offers 2 units at 1.5 usdc each
+    try {
+      let initParams = {
+        units: new anchor.BN(2),
+        price: new anchor.BN(1_500_000), // 1.5 usdc, fixed-point quote value
+      };
+
+      const tx = await program.methods
+        .postAsk(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          order: order,
+          orderEscrow: orderEscrow,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the buyer takes 1 of the 2 units, leaving the rest of the ask open
+    try {
+      let initParams = {
+        units: new anchor.BN(1),
+      };
+
+      const tx = await program.methods
+        .fillAsk(initParams)
+        .accounts({
+          owner: buyerOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          order: order,
+          orderEscrow: orderEscrow,
+          sellerInvestor: investor,
+          sellerHolding: holding,
+          recipientTokens: investorOwnerATA.address,
+          investor: buyer,
+          holding: buyerHolding,
+          senderTokens: buyerOwnerATA.address,
+          investorUnits: buyerUnitsATA.address,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([buyerOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.order.fetch(order);
+      console.log("order: ", result);
+
+      let result2 = await program.account.holding.fetch(buyerHolding);
+      console.log("buyer holding: ", result2);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the seller takes back the unit left in the ask
+    try {
+      const tx = await program.methods
+        .cancelAsk()
+        .accounts({
+          owner: investorOwner.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          order: order,
+          orderEscrow: orderEscrow,
+          holding: holding,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is add liquidity and swap!", async () => {
+    let [pool] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("pool"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [unitVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("pool-units"), pool.toBuffer()],
+      program.programId
+    );
+    let [quoteVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("pool-quote"), pool.toBuffer()],
+      program.programId
+    );
+    let [liquidityProvider] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("liquidity-provider"),
+        pool.toBuffer(),
+        investor.toBuffer(),
+      ],
+      program.programId
+    );
+
+    try {
+      let initParams = {
+        feeBps: 30, // 0.3% swap fee for liquidity providers
+        priceBandBps: 10000, // wide band for the small test pool
+      };
+
+      const tx = await program.methods
+        .configurePool(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          pool: pool,
+          unitVault: unitVault,
+          quoteVault: quoteVault,
+          unitMint: mintToken,
+          quoteMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the promoter whitelists the first investor as a liquidity provider
+    try {
+      let initParams = {
+        whitelisted: true,
+      };
+
+      const tx = await program.methods
+        .setLiquidityProvider(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          pool: pool,
+          investor: investor,
+          liquidityProvider: liquidityProvider,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the first deposit prices the pool at NAV i.e 1 usdc per unit
+    try {
+      let initParams = {
+        units: new anchor.BN(4),
+        maxQuote: new anchor.BN(4_000_000), // 4 usdc, fixed-point quote value
+      };
+
+      const tx = await program.methods
+        .addLiquidity(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          pool: pool,
+          liquidityProvider: liquidityProvider,
+          investor: investor,
+          holding: holding,
+          unitVault: unitVault,
+          quoteVault: quoteVault,
+          investorUnits: investorUnitsATA.address,
+          senderTokens: investorOwnerATA.address,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        direction: { buyUnits: {} },
+        units: new anchor.BN(1),
+        quoteLimit: new anchor.BN(2_000_000), // pay at most 2 usdc
+      };
+
+      const tx = await program.methods
+        .swap(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          pool: pool,
+          investor: investor,
+          holding: holding,
+          unitVault: unitVault,
+          quoteVault: quoteVault,
+          investorUnits: investorUnitsATA.address,
+          investorTokens: investorOwnerATA.address,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.pool.fetch(pool);
+      console.log("pool: ", result);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is freeze, thaw and force transfer holding!", async () => {
+    let [distribution] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("distribution"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [distributionVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("distribution-vault"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [pool] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("pool"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [unitVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("pool-units"), pool.toBuffer()],
+      program.programId
+    );
+    let [quoteVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("pool-quote"), pool.toBuffer()],
+      program.programId
+    );
+    let [liquidityProvider] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("liquidity-provider"),
+        pool.toBuffer(),
+        investor.toBuffer(),
+      ],
+      program.programId
+    );
+    // second ask posted on the scheme i.e index 1
+    const orderIndex = new anchor.BN(1);
+    let [order] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("order"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        orderIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [orderEscrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [anchor.utils.bytes.utf8.encode("order-escrow"), order.toBuffer()],
+      program.programId
+    );
+    let [redemptionRequest] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        investor.toBuffer(),
+      ],
+      program.programId
+    );
+    let [redemptionEscrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption-escrow"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    // the promoter, registered as an investor in the token transfer test, tries to fill the ask
+    let [promoterInvestor] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("investor"),
+        trustSchemePromoter.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    let [promoterHolding] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("holding"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        promoterInvestor.toBuffer(),
+      ],
+      program.programId
+    );
+    let promoterOwnerATA: Account; // promoter's usdc token account
+    try {
+      promoterOwnerATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        usdcMint,
+        trustSchemePromoter.publicKey
+      );
+    } catch (error) {
+      console.log(error);
+    }
+    // third redemption window of the scheme i.e index 2
+    const redemptionWindowIndex = new anchor.BN(2);
+    let [redemptionWindow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("redemption-window"),
+        realEstateInvestmentTrustScheme.toBuffer(),
+        redemptionWindowIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    // an open ask and a closed redemption window to settle while frozen
+    try {
+      const tx = await program.methods
+        .postAsk({
+          units: new anchor.BN(1),
+          price: new anchor.BN(1_500_000), // 1.5 usdc, fixed-point quote value
+        })
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          order: order,
+          orderEscrow: orderEscrow,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+
+      const tx2 = await program.methods
+        .sellInvestmentTrusts({ amount: new anchor.BN(1) })
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          redemptionRequest: redemptionRequest,
+          redemptionEscrow: redemptionEscrow,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx2);
+
+      const tx3 = await program.methods
+        .closeRedemptionWindow()
+        .accounts({
+          owner: payer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          redemptionWindow: redemptionWindow,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx3);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        frozen: true,
+        reason: { courtOrder: {} },
+      };
+
+      const tx = await program.methods
+        .setHoldingFrozen(initParams)
+        .accounts({
+          owner: complianceOfficer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          holding: holding,
+          holdingUnits: investorUnitsATA.address,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.holding.fetch(holding);
+      console.log("holding: ", result);
+      assert.equal(result.frozen, true);
+    } catch (error) {
+      console.log(error);
+    }
+
+    const settleRedemption = program.methods.settleRedemption().accounts({
+      owner: payer.publicKey,
+      realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+      redemptionWindow: redemptionWindow,
+      redemptionRequest: redemptionRequest,
+      investor: investor,
+      holding: holding,
+      redemptionEscrow: redemptionEscrow,
+      unitMint: mintToken,
+      senderTokens: settlementVault,
+      recipientTokens: investorOwnerATA.address,
+      mintToken: usdcMint,
+      depositAccount: depositAccount,
+      pdaAuth: pdaAuth,
+      treasuryVault: treasuryVault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    });
+    const cancelAsk = program.methods
+      .cancelAsk()
+      .accounts({
+        owner: investorOwner.publicKey,
+        realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+        order: order,
+        orderEscrow: orderEscrow,
+        holding: holding,
+        unitMint: mintToken,
+        investorUnits: investorUnitsATA.address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associateTokenProgram: associateTokenProgram,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([investorOwner]);
+
+    // a frozen holding cannot take distributions, pool withdrawals, cancelled
+    // or filled asks or redemptions until it is thawed
+    const frozenCalls = {
+      claimDistribution: program.methods
+        .claimDistribution()
+        .accounts({
+          owner: investorOwner.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          distribution: distribution,
+          distributionVault: distributionVault,
+          recipientTokens: investorOwnerATA.address,
+          mintToken: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner]),
+      reinvestDistributions: program.methods
+        .reinvestDistributions()
+        .accounts({
+          owner: payer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          investor: investor,
+          holding: holding,
+          distribution: distribution,
+          distributionVault: distributionVault,
+          settlementVault: settlementVault,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        }),
+      removeLiquidity: program.methods
+        .removeLiquidity({ shares: new anchor.BN(1) })
+        .accounts({
+          owner: investorOwner.publicKey,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          pool: pool,
+          liquidityProvider: liquidityProvider,
+          investor: investor,
+          holding: holding,
+          unitVault: unitVault,
+          quoteVault: quoteVault,
+          investorUnits: investorUnitsATA.address,
+          recipientTokens: investorOwnerATA.address,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner]),
+      cancelAsk: cancelAsk,
+      fillAsk: program.methods
+        .fillAsk({ units: new anchor.BN(1) })
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          order: order,
+          orderEscrow: orderEscrow,
+          sellerInvestor: investor,
+          sellerHolding: holding,
+          recipientTokens: investorOwnerATA.address,
+          investor: promoterInvestor,
+          holding: promoterHolding,
+          senderTokens: promoterOwnerATA.address,
+          investorUnits: promoterUnitsATA.address,
+          mintToken: usdcMint,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter]),
+      sellInvestmentTrusts: program.methods
+        .sellInvestmentTrusts({ amount: new anchor.BN(1) })
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          investor: investor,
+          holding: holding,
+          redemptionRequest: redemptionRequest,
+          redemptionEscrow: redemptionEscrow,
+          unitMint: mintToken,
+          investorUnits: investorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associateTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner]),
+      settleRedemption: settleRedemption,
+    };
+
+    for (let [method, call] of Object.entries(frozenCalls)) {
+      try {
+        await call.rpc();
+        assert.fail(`${method} accepted a frozen holding`);
+      } catch (error) {
+        console.log(error);
+        assert.equal(error.error?.errorCode?.code, "HoldingFrozen");
+      }
+    }
+
+    try {
+      let initParams = {
+        frozen: false,
+        reason: { courtOrder: {} },
+      };
+
+      const tx = await program.methods
+        .setHoldingFrozen(initParams)
+        .accounts({
+          owner: complianceOfficer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          holding: holding,
+          holdingUnits: investorUnitsATA.address,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // once thawed the ask can be cancelled and the redemption settled
+    try {
+      const tx = await cancelAsk.rpc();
+      console.log("Your transaction signature", tx);
+
+      const tx2 = await settleRedemption.rpc();
+      console.log("Your transaction signature", tx2);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // the first scheme's unit mint is an SPL Token mint without a permanent
+    // delegate, so forced transfers are rejected with ForcedTransferNotEnabled
+    try {
+      let initParams = {
+        units: new anchor.BN(1),
+        reason: { lostKey: {} },
+      };
+
+      const tx = await program.methods
+        .forceTransfer(initParams)
+        .accounts({
+          owner: complianceOfficer.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: realEstateInvestmentTrustScheme,
+          jurisdictionPolicy: jurisdictionPolicy,
+          fromInvestor: investor,
+          fromHolding: holding,
+          fromUnits: investorUnitsATA.address,
+          toInvestor: investor,
+          toHolding: holding,
+          toUnits: investorUnitsATA.address,
+          unitMint: mintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is primary offering of third investment trust scheme!", async () => {
+    // third scheme of the same promoter i.e scheme id 2
+    const thirdSchemeId = new anchor.BN(2);
+    let [thirdScheme] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("investment-trust-scheme"),
+        trustSchemePromoter.publicKey.toBuffer(),
+        thirdSchemeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [thirdIssuerRegistryEntry] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("issuer-registry"),
+          thirdScheme.toBuffer(),
+        ],
+        program.programId
       );
-      console.log("real estate investment trust scheme: ", result3);
+    let [thirdDepositAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("deposit-base"),
+        thirdScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [thirdPdaAuth] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("auth"),
+        thirdDepositAccount.toBuffer(),
+      ],
+      program.programId
+    );
+    let [thirdTreasuryVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("treasury-vault"),
+        thirdPdaAuth.toBuffer(),
+      ],
+      program.programId
+    );
+    let thirdSettlementVault = anchor.utils.token.associatedAddress({
+      mint: usdcMint,
+      owner: thirdTreasuryVault,
+    });
+    let [thirdMintToken] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("unit-mint"),
+        thirdScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [offeringEscrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("offering-escrow"),
+        thirdScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [subscription] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("subscription"),
+        thirdScheme.toBuffer(),
+        investor.toBuffer(),
+      ],
+      program.programId
+    );
+    let [thirdHolding] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("holding"),
+        thirdScheme.toBuffer(),
+        investor.toBuffer(),
+      ],
+      program.programId
+    );
+
+    try {
+      let initParams = {
+        schemeId: thirdSchemeId,
+        issuer: {
+          issuer: "Acorn Holdings Limited",
+          name: "Acorn I-REIT",
+          typeOfReit: 2, // IncomeRealEstateInvestmentTrust
+          listingDate: "May 2024",
+        },
+        country: "KE",
+        unitCostOfInvestmentTrusts: new anchor.BN(1_000_000),
+        decimals: 9,
+        lockUpPeriod: new anchor.BN(0),
+      };
+
+      const tx = await program.methods
+        .registerInvestmentTrustScheme(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          schemePromoter: schemePromoter,
+          realEstateInvestmentTrustScheme: thirdScheme,
+          issuerRegistryEntry: thirdIssuerRegistryEntry,
+          depositAccount: thirdDepositAccount,
+          pdaAuth: thirdPdaAuth,
+          treasuryVault: thirdTreasuryVault,
+          quoteMint: usdcMint,
+          settlementVault: thirdSettlementVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    for (let status of [
+      { underReview: {} },
+      { approved: {} },
+    ]) {
+      try {
+        const tx = await program.methods
+          .setSchemeStatus({ status: status })
+          .accounts({
+            owner: schemeApprover.publicKey,
+            investmentTrustsConfigs: investmentTrustsConfigs,
+            realEstateInvestmentTrustScheme: thirdScheme,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([schemeApprover])
+          .rpc();
+        console.log("Your transaction signature", tx);
+      } catch (error) {
+        console.log(error);
+      }
+    }
+
+    let thirdInvestorUnitsATA: Account;
+    try {
+      const tx = await program.methods
+        .createToken()
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: thirdScheme,
+          mintToken: thirdMintToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+
+      thirdInvestorUnitsATA = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        thirdMintToken,
+        investorOwner.publicKey
+      );
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const now = Math.floor(Date.now() / 1000);
+      let initParams = {
+        softCap: new anchor.BN(5_000_000),
+        hardCap: new anchor.BN(10_000_000),
+        minTicket: new anchor.BN(1_000_000),
+        startTime: new anchor.BN(now - 60),
+        endTime: new anchor.BN(now + 3600), // one hour offering window
+      };
+
+      const tx = await program.methods
+        .configureOffering(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: thirdScheme,
+          quoteMint: usdcMint,
+          offeringEscrow: offeringEscrow,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let initParams = {
+        // fills the hard cap so the offering closes early
+        amount: new anchor.BN(10_000_000),
+      };
+
+      const tx = await program.methods
+        .subscribeOffering(initParams)
+        .accounts({
+          owner: investorOwner.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          realEstateInvestmentTrustScheme: thirdScheme,
+          jurisdictionPolicy: null,
+          investor: investor,
+          subscription: subscription,
+          senderTokens: investorOwnerATA.address,
+          offeringEscrow: offeringEscrow,
+          mintToken: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const tx = await program.methods
+        .finalizeOffering()
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: thirdScheme,
+          offeringEscrow: offeringEscrow,
+          settlementVault: thirdSettlementVault,
+          mintToken: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const tx = await program.methods
+        .claimSubscription()
+        .accounts({
+          owner: investorOwner.publicKey,
+          realEstateInvestmentTrustScheme: thirdScheme,
+          investor: investor,
+          subscription: subscription,
+          holding: thirdHolding,
+          unitMint: thirdMintToken,
+          investorUnits: thirdInvestorUnitsATA.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([investorOwner])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    // refunds are only paid out when the offering misses its soft cap
+    try {
+      await program.methods
+        .refundSubscription()
+        .accounts({
+          owner: investorOwner.publicKey,
+          realEstateInvestmentTrustScheme: thirdScheme,
+          subscription: subscription,
+          offeringEscrow: offeringEscrow,
+          recipientTokens: investorOwnerATA.address,
+          mintToken: usdcMint,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([investorOwner])
+        .rpc();
+      assert.fail("refundSubscription accepted a successful offering");
+    } catch (error) {
+      console.log(error);
+      assert.equal(error.error?.errorCode?.code, "OfferingNotFailed");
+    }
+
+    try {
+      let result = await program.account.realEstateInvestmentTrustScheme.fetch(
+        thirdScheme
+      );
+      console.log("real estate investment trust scheme: ", result);
+
+      let result2 = await program.account.subscription.fetch(subscription);
+      console.log("subscription: ", result2);
+
+      let result3 = await program.account.holding.fetch(thirdHolding);
+      console.log("holding: ", result3);
+    } catch (error) {
+      console.log(error);
+    }
+  });
+
+  it("Is create unit mint with transfer hook and metadata!", async () => {
+    // fourth scheme of the same promoter i.e scheme id 3
+    const fourthSchemeId = new anchor.BN(3);
+    let [fourthScheme] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("investment-trust-scheme"),
+        trustSchemePromoter.publicKey.toBuffer(),
+        fourthSchemeId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    let [fourthIssuerRegistryEntry] =
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("issuer-registry"),
+          fourthScheme.toBuffer(),
+        ],
+        program.programId
+      );
+    let [fourthDepositAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("deposit-base"),
+        fourthScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [fourthPdaAuth] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("auth"),
+        fourthDepositAccount.toBuffer(),
+      ],
+      program.programId
+    );
+    let [fourthTreasuryVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("treasury-vault"),
+        fourthPdaAuth.toBuffer(),
+      ],
+      program.programId
+    );
+    let fourthSettlementVault = anchor.utils.token.associatedAddress({
+      mint: usdcMint,
+      owner: fourthTreasuryVault,
+    });
+    let [fourthMintToken] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("unit-mint"),
+        fourthScheme.toBuffer(),
+      ],
+      program.programId
+    );
+    let [extraAccountMetaList] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        anchor.utils.bytes.utf8.encode("extra-account-metas"),
+        fourthMintToken.toBuffer(),
+      ],
+      program.programId
+    );
+
+    try {
+      let initParams = {
+        schemeId: fourthSchemeId,
+        issuer: {
+          issuer: "Acorn Holdings Limited",
+          name: "Acorn Hooked D-REIT",
+          typeOfReit: 1, // DevelopmentRealEstateInvestmentTrust
+          listingDate: "June 2024",
+        },
+        country: "KE",
+        unitCostOfInvestmentTrusts: new anchor.BN(1_000_000),
+        decimals: 9,
+        lockUpPeriod: new anchor.BN(0),
+      };
+
+      const tx = await program.methods
+        .registerInvestmentTrustScheme(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          investmentTrustsConfigs: investmentTrustsConfigs,
+          schemePromoter: schemePromoter,
+          realEstateInvestmentTrustScheme: fourthScheme,
+          issuerRegistryEntry: fourthIssuerRegistryEntry,
+          depositAccount: fourthDepositAccount,
+          pdaAuth: fourthPdaAuth,
+          treasuryVault: fourthTreasuryVault,
+          quoteMint: usdcMint,
+          settlementVault: fourthSettlementVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: associateTokenProgram,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      // the unit mint is created under Token-2022 with this program as its transfer hook
+      const tx = await program.methods
+        .createToken()
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: fourthScheme,
+          mintToken: fourthMintToken,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      const tx = await program.methods
+        .initializeExtraAccountMetaList()
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: fourthScheme,
+          mintToken: fourthMintToken,
+          extraAccountMetaList: extraAccountMetaList,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      // only the name and disclosures uri change, the REIT type and listing
+      // date stay as registered
+      let initParams = {
+        name: "Acorn Hooked D-REIT II",
+        uri: "https://example.com/acorn-hooked-d-reit/disclosures-2.json",
+      };
+
+      const tx = await program.methods
+        .updateTokenMetadata(initParams)
+        .accounts({
+          owner: trustSchemePromoter.publicKey,
+          realEstateInvestmentTrustScheme: fourthScheme,
+          issuerRegistryEntry: fourthIssuerRegistryEntry,
+          mintToken: fourthMintToken,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([trustSchemePromoter])
+        .rpc();
+      console.log("Your transaction signature", tx);
+    } catch (error) {
+      console.log(error);
+    }
+
+    try {
+      let result = await program.account.realEstateInvestmentTrustScheme.fetch(
+        fourthScheme
+      );
+      console.log("real estate investment trust scheme: ", result);
+      assert.equal(result.unitTransferHook, true);
+      assert.equal(result.unitMetadata, true);
+      assert.equal(result.unitPermanentDelegate, true);
+      assert.equal(result.issuer.name, "Acorn Hooked D-REIT II");
+      assert.equal(result.issuer.typeOfReit, 1);
+      assert.equal(result.issuer.listingDate, "June 2024");
     } catch (error) {
       console.log(error);
     }